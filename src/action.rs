@@ -0,0 +1,135 @@
+//! The set of messages the UI can act on, and the dispatch spec string
+//! format used to parse them out of a user keymap (see `keymap.rs`).
+//!
+//! Keeping `Action` independent of any particular input source is what
+//! lets `State::apply` be driven by a keyboard today and, eventually, by
+//! anything else (a script, a remote control channel) that can produce
+//! the same enum.
+
+use crate::{HelpType, InputMode};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    ChangeMode(InputMode),
+    ShowHelp(HelpType),
+    Cancel,
+    NextItem,
+    PrevItem,
+    MoveFirst,
+    MoveLast,
+    PageUp,
+    PageDown,
+    SelectFolder,
+    SelectModel,
+    SelectTenant,
+    ExecuteSearch,
+    ReloadFolders,
+    ReloadModels,
+    CycleSort,
+    ReverseSort,
+    ToggleStatusFilter,
+    ExpandNode,
+    CollapseNode,
+    RunScript(String),
+    Noop,
+}
+
+impl Action {
+    pub fn parse(spec: &str) -> Option<Action> {
+        let spec = spec.trim();
+        if let Some(mode) = spec.strip_prefix("ChangeMode:") {
+            return Some(Action::ChangeMode(parse_mode(mode)?));
+        }
+        if let Some(help_type) = spec.strip_prefix("ShowHelp:") {
+            return Some(Action::ShowHelp(parse_help_type(help_type)?));
+        }
+        if let Some(name) = spec.strip_prefix("RunScript:") {
+            return Some(Action::RunScript(name.to_string()));
+        }
+        match spec {
+            "Quit" => Some(Action::Quit),
+            "Cancel" => Some(Action::Cancel),
+            "NextItem" => Some(Action::NextItem),
+            "PrevItem" => Some(Action::PrevItem),
+            "MoveFirst" => Some(Action::MoveFirst),
+            "MoveLast" => Some(Action::MoveLast),
+            "PageUp" => Some(Action::PageUp),
+            "PageDown" => Some(Action::PageDown),
+            "SelectFolder" => Some(Action::SelectFolder),
+            "SelectModel" => Some(Action::SelectModel),
+            "SelectTenant" => Some(Action::SelectTenant),
+            "ExecuteSearch" => Some(Action::ExecuteSearch),
+            "ReloadFolders" => Some(Action::ReloadFolders),
+            "ReloadModels" => Some(Action::ReloadModels),
+            "CycleSort" => Some(Action::CycleSort),
+            "ReverseSort" => Some(Action::ReverseSort),
+            "ToggleStatusFilter" => Some(Action::ToggleStatusFilter),
+            "ExpandNode" => Some(Action::ExpandNode),
+            "CollapseNode" => Some(Action::CollapseNode),
+            _ => None,
+        }
+    }
+
+    /// Short description used when generating a mode's help text.
+    pub fn description(&self) -> String {
+        match self {
+            Action::Quit => String::from("Exit the program"),
+            Action::ChangeMode(InputMode::Folder) => String::from("Switch to Folder mode"),
+            Action::ChangeMode(InputMode::Model) => String::from("Switch to Model mode"),
+            Action::ChangeMode(InputMode::Match) => String::from("Switch to Match mode"),
+            Action::ChangeMode(InputMode::Search) => String::from("Switch to Search mode"),
+            Action::ChangeMode(InputMode::Tenant) => String::from("Select Physna tenant"),
+            Action::ChangeMode(InputMode::Normal) => String::from("Return to Normal mode"),
+            Action::ChangeMode(InputMode::Help) => String::from("Show help"),
+            Action::ShowHelp(_) => String::from("Show help for the current mode"),
+            Action::Cancel => String::from("Exit to Normal mode"),
+            Action::NextItem => String::from("Move selection down"),
+            Action::PrevItem => String::from("Move selection up"),
+            Action::MoveFirst => String::from("Jump to the first item"),
+            Action::MoveLast => String::from("Jump to the last item"),
+            Action::PageUp => String::from("Jump up a page"),
+            Action::PageDown => String::from("Jump down a page"),
+            Action::SelectFolder => String::from("Select the highlighted folder"),
+            Action::SelectModel => String::from("Select the highlighted model"),
+            Action::SelectTenant => String::from("Select the highlighted tenant"),
+            Action::ExecuteSearch => String::from("Execute search"),
+            Action::ReloadFolders => String::from("Reload the list of folders"),
+            Action::ReloadModels => String::from("Reload the list of models"),
+            Action::CycleSort => String::from("Cycle the sort column/direction"),
+            Action::ReverseSort => String::from("Reverse the current sort direction"),
+            Action::ToggleStatusFilter => {
+                String::from("Show only models matching the selected Status")
+            }
+            Action::ExpandNode => String::from("Expand the selected folder"),
+            Action::CollapseNode => String::from("Collapse the selected folder"),
+            Action::RunScript(name) => format!("Run the \"{}\" script command", name),
+            Action::Noop => String::from(""),
+        }
+    }
+}
+
+pub fn parse_mode(spec: &str) -> Option<InputMode> {
+    match spec.trim().to_lowercase().as_str() {
+        "normal" => Some(InputMode::Normal),
+        "search" => Some(InputMode::Search),
+        "folder" => Some(InputMode::Folder),
+        "model" => Some(InputMode::Model),
+        "match" => Some(InputMode::Match),
+        "help" => Some(InputMode::Help),
+        "tenant" => Some(InputMode::Tenant),
+        _ => None,
+    }
+}
+
+pub fn parse_help_type(spec: &str) -> Option<HelpType> {
+    match spec.trim().to_lowercase().as_str() {
+        "general" => Some(HelpType::General),
+        "search" => Some(HelpType::Search),
+        "folder" => Some(HelpType::Folder),
+        "model" => Some(HelpType::Model),
+        "match" => Some(HelpType::Match),
+        "tenant" => Some(HelpType::Tenant),
+        _ => None,
+    }
+}
@@ -0,0 +1,181 @@
+//! An embedded SQLite cache for folder/model listings, keyed by
+//! `(tenant, folder_id)`, so flipping back to an already-visited folder
+//! doesn't have to re-hit Physna. Each entry stores the serialized model
+//! list plus the time it was fetched; an entry older than the configured
+//! TTL is treated as a miss, just like it was never cached.
+
+use pcli::model::Model;
+use rusqlite::{params, Connection};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_TTL_SECONDS: u64 = 300;
+
+pub struct ModelCache {
+    connection: Connection,
+    ttl_seconds: u64,
+}
+
+impl ModelCache {
+    /// Opens (creating if necessary) the SQLite database at `path` and
+    /// migrates it to the current schema.
+    pub fn open(path: &Path) -> Result<ModelCache, rusqlite::Error> {
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+
+        let connection = Connection::open(path)?;
+        connection.execute(
+            "CREATE TABLE IF NOT EXISTS model_cache (
+                tenant TEXT NOT NULL,
+                folder_id INTEGER NOT NULL,
+                models_json TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY (tenant, folder_id)
+            )",
+            [],
+        )?;
+
+        let ttl_seconds = std::env::var("PTUI_CACHE_TTL_SECONDS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_TTL_SECONDS);
+
+        Ok(ModelCache {
+            connection,
+            ttl_seconds,
+        })
+    }
+
+    /// Returns the cached models for `(tenant, folder_id)`, unless there
+    /// is no entry or it is older than the configured TTL.
+    pub fn get(&self, tenant: &str, folder_id: u32) -> Option<Vec<Model>> {
+        let row: Result<(String, i64), rusqlite::Error> = self.connection.query_row(
+            "SELECT models_json, fetched_at FROM model_cache WHERE tenant = ?1 AND folder_id = ?2",
+            params![tenant, folder_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        );
+
+        let (models_json, fetched_at) = row.ok()?;
+        if now_unix().saturating_sub(fetched_at as u64) > self.ttl_seconds {
+            return None;
+        }
+
+        serde_json::from_str(&models_json).ok()
+    }
+
+    /// Replaces the cached entry for `(tenant, folder_id)` with `models`,
+    /// stamped with the current time.
+    pub fn put(
+        &self,
+        tenant: &str,
+        folder_id: u32,
+        models: &[Model],
+    ) -> Result<(), rusqlite::Error> {
+        let models_json = serde_json::to_string(models)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+
+        self.connection.execute(
+            "INSERT INTO model_cache (tenant, folder_id, models_json, fetched_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(tenant, folder_id) DO UPDATE SET
+                models_json = excluded.models_json,
+                fetched_at = excluded.fetched_at",
+            params![tenant, folder_id, models_json, now_unix() as i64],
+        )?;
+
+        Ok(())
+    }
+}
+
+fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// The default location of the cache database, under the user's data
+/// directory (falling back to the home directory if that can't be
+/// determined).
+pub fn default_cache_path() -> PathBuf {
+    let mut path = dirs::data_dir().or_else(dirs::home_dir).unwrap_or_default();
+    path.push("ptui");
+    path.push("cache.sqlite3");
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `pcli::model::Model` isn't available to this sandbox; `name`,
+    // `state` and `uuid` are the only fields ever read from it
+    // elsewhere in this crate, so that's what this literal assumes.
+    fn model(name: &str, state: &str, uuid: &str) -> Model {
+        Model {
+            name: name.to_string(),
+            state: state.to_string(),
+            uuid: uuid.to_string(),
+        }
+    }
+
+    fn temp_cache_path(label: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let mut path = std::env::temp_dir();
+        path.push(format!("ptui_test_cache_{}_{}.sqlite3", label, nanos));
+        path
+    }
+
+    #[test]
+    fn test_put_then_get_roundtrips_within_ttl() {
+        let path = temp_cache_path("roundtrip");
+        let cache = ModelCache::open(&path).unwrap();
+        let models = vec![model("widget", "ready", "uuid-1")];
+
+        cache.put("acme", 42, &models).unwrap();
+        let fetched = cache.get("acme", 42).unwrap();
+
+        assert_eq!(fetched.len(), 1);
+        assert_eq!(fetched[0].name, "widget");
+        assert_eq!(fetched[0].uuid, "uuid-1");
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_get_returns_none_for_a_missing_entry() {
+        let path = temp_cache_path("missing");
+        let cache = ModelCache::open(&path).unwrap();
+
+        assert!(cache.get("acme", 42).is_none());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_get_returns_none_once_the_entry_is_older_than_the_ttl() {
+        let path = temp_cache_path("expired");
+        let mut cache = ModelCache::open(&path).unwrap();
+        cache.ttl_seconds = 60;
+        let models = vec![model("widget", "ready", "uuid-1")];
+        cache.put("acme", 42, &models).unwrap();
+
+        // Back-date the entry past the TTL instead of sleeping for it.
+        let stale = now_unix() as i64 - 61;
+        cache
+            .connection
+            .execute(
+                "UPDATE model_cache SET fetched_at = ?1 WHERE tenant = ?2 AND folder_id = ?3",
+                params![stale, "acme", 42],
+            )
+            .unwrap();
+
+        assert!(cache.get("acme", 42).is_none());
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
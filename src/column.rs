@@ -0,0 +1,157 @@
+//! Column-aware sorting and filtering for `StatefulTable`, layered on top
+//! of the fuzzy-match view from `fuzzy.rs`. Mirrors xplr's
+//! `NodeSorter`/`NodeFilter` applicables: a sorter names a column plus a
+//! direction, a filter is a named predicate, and both are re-applied
+//! whenever the table's view is recomputed.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortColumn {
+    Name,
+    Status,
+    Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnSorter {
+    pub column: SortColumn,
+    pub direction: SortDirection,
+}
+
+/// The order `models_section` renders columns in, so a sorter's
+/// `column` can be matched back to the header cell it applies to.
+pub const COLUMN_ORDER: [SortColumn; 3] = [SortColumn::Name, SortColumn::Status, SortColumn::Uuid];
+
+/// The header label a column is rendered with.
+pub fn column_label(column: SortColumn) -> &'static str {
+    match column {
+        SortColumn::Name => "Name",
+        SortColumn::Status => "Status",
+        SortColumn::Uuid => "UUID",
+    }
+}
+
+impl SortDirection {
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "\u{25B2}",
+            SortDirection::Descending => "\u{25BC}",
+        }
+    }
+}
+
+const SORT_CYCLE: [(SortColumn, SortDirection); 6] = [
+    (SortColumn::Name, SortDirection::Ascending),
+    (SortColumn::Name, SortDirection::Descending),
+    (SortColumn::Status, SortDirection::Ascending),
+    (SortColumn::Status, SortDirection::Descending),
+    (SortColumn::Uuid, SortDirection::Ascending),
+    (SortColumn::Uuid, SortDirection::Descending),
+];
+
+impl ColumnSorter {
+    /// Advances through name asc/desc, status asc/desc, uuid asc/desc,
+    /// then back to no sort (`None`) at all.
+    pub fn cycle(current: Option<ColumnSorter>) -> Option<ColumnSorter> {
+        let next_position = match current {
+            None => 0,
+            Some(sorter) => {
+                let position = SORT_CYCLE
+                    .iter()
+                    .position(|&(column, direction)| {
+                        column == sorter.column && direction == sorter.direction
+                    })
+                    .unwrap_or(SORT_CYCLE.len() - 1);
+                position + 1
+            }
+        };
+
+        SORT_CYCLE
+            .get(next_position)
+            .map(|&(column, direction)| ColumnSorter { column, direction })
+    }
+
+    /// Flips direction without changing the column.
+    pub fn reversed(&self) -> ColumnSorter {
+        ColumnSorter {
+            column: self.column,
+            direction: match self.direction {
+                SortDirection::Ascending => SortDirection::Descending,
+                SortDirection::Descending => SortDirection::Ascending,
+            },
+        }
+    }
+
+    /// A short "Column ▲" label for the status line.
+    pub fn describe(&self) -> String {
+        format!("{} {}", column_label(self.column), self.direction.arrow())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnFilter {
+    StatusEquals(String),
+    NameContains(String),
+}
+
+impl ColumnFilter {
+    pub fn matches<T: Columnar>(&self, item: &T) -> bool {
+        match self {
+            ColumnFilter::StatusEquals(value) => item
+                .column_value(SortColumn::Status)
+                .eq_ignore_ascii_case(value),
+            ColumnFilter::NameContains(value) => item
+                .column_value(SortColumn::Name)
+                .to_lowercase()
+                .contains(&value.to_lowercase()),
+        }
+    }
+}
+
+/// Implemented by anything that can appear in a sortable/filterable
+/// `StatefulTable`, exposing its columns by name rather than position.
+pub trait Columnar {
+    fn column_value(&self, column: SortColumn) -> String;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_sorter_cycle_visits_every_column_and_direction() {
+        let mut sorter = ColumnSorter::cycle(None);
+        assert_eq!(
+            sorter,
+            Some(ColumnSorter {
+                column: SortColumn::Name,
+                direction: SortDirection::Ascending
+            })
+        );
+
+        for &(column, direction) in SORT_CYCLE.iter().skip(1) {
+            sorter = ColumnSorter::cycle(sorter);
+            assert_eq!(sorter, Some(ColumnSorter { column, direction }));
+        }
+
+        // One more cycle past the last entry returns to "no sort".
+        assert_eq!(ColumnSorter::cycle(sorter), None);
+    }
+
+    #[test]
+    fn test_column_sorter_reversed_flips_direction_only() {
+        let sorter = ColumnSorter {
+            column: SortColumn::Status,
+            direction: SortDirection::Ascending,
+        };
+        let reversed = sorter.reversed();
+        assert_eq!(reversed.column, SortColumn::Status);
+        assert_eq!(reversed.direction, SortDirection::Descending);
+        assert_eq!(reversed.reversed(), sorter);
+    }
+}
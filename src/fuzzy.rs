@@ -0,0 +1,162 @@
+//! A small subsequence-based fuzzy matcher used to incrementally filter
+//! the folder list and models table as the user types in Search mode.
+
+const MATCH_SCORE: i32 = 16;
+const CONSECUTIVE_BONUS: i32 = 8;
+const SEPARATOR_BONUS: i32 = 10;
+const CAMEL_CASE_BONUS: i32 = 10;
+const GAP_PENALTY: i32 = 2;
+const LEADING_PENALTY: i32 = 1;
+
+fn is_separator(c: char) -> bool {
+    matches!(c, '/' | '_' | '-' | ' ')
+}
+
+/// Scores how well `candidate` matches `query` as a case-insensitive
+/// subsequence. Returns `None` when `query` isn't a subsequence of
+/// `candidate` at all; otherwise a higher score means a tighter match
+/// (consecutive runs and matches right after a separator or a
+/// lower->upper camelCase boundary score best, gaps and leading
+/// unmatched characters are penalized).
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query_chars: Vec<char> = query.chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+
+    let mut score = 0i32;
+    let mut query_index = 0usize;
+    let mut last_matched_index: Option<usize> = None;
+    let mut consecutive = 0i32;
+
+    for (candidate_index, &c) in candidate_chars.iter().enumerate() {
+        if query_index >= query_chars.len() {
+            break;
+        }
+
+        if !c.to_lowercase().eq(query_chars[query_index].to_lowercase()) {
+            continue;
+        }
+
+        match last_matched_index {
+            None => score -= candidate_index as i32 * LEADING_PENALTY,
+            Some(previous) => {
+                let gap = candidate_index - previous - 1;
+                if gap > 0 {
+                    score -= gap as i32 * GAP_PENALTY;
+                    consecutive = 0;
+                }
+            }
+        }
+
+        if last_matched_index == Some(candidate_index.wrapping_sub(1)) {
+            consecutive += 1;
+        } else {
+            consecutive = 1;
+        }
+        score += consecutive * CONSECUTIVE_BONUS;
+
+        if candidate_index > 0 {
+            let previous_char = candidate_chars[candidate_index - 1];
+            if is_separator(previous_char) {
+                score += SEPARATOR_BONUS;
+            } else if previous_char.is_lowercase() && c.is_uppercase() {
+                score += CAMEL_CASE_BONUS;
+            }
+        }
+
+        score += MATCH_SCORE;
+        last_matched_index = Some(candidate_index);
+        query_index += 1;
+    }
+
+    if query_index == query_chars.len() {
+        Some(score)
+    } else {
+        None
+    }
+}
+
+/// Implemented by anything that can appear in a fuzzy-filterable list,
+/// returning the text the query is matched against.
+pub trait Searchable {
+    fn search_text(&self) -> &str;
+}
+
+impl Searchable for String {
+    fn search_text(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Scores and ranks `items` against `query`, returning the indices of
+/// the matches sorted by descending score. An empty query matches every
+/// index, in original order.
+pub fn filter_indices<T: Searchable>(items: &[T], query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return (0..items.len()).collect();
+    }
+
+    let mut scored: Vec<(usize, i32)> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            fuzzy_match(query, item.search_text()).map(|score| (index, score))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.1.cmp(&a.1));
+    scored.into_iter().map(|(index, _)| index).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match_requires_subsequence() {
+        assert_eq!(fuzzy_match("", "anything"), Some(0));
+        assert!(fuzzy_match("xyz", "abc").is_none());
+        assert!(fuzzy_match("ac", "abc").is_some());
+        assert!(fuzzy_match("ca", "abc").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_match_is_case_insensitive() {
+        assert!(fuzzy_match("ABC", "abc").is_some());
+        assert_eq!(fuzzy_match("abc", "abc"), fuzzy_match("ABC", "abc"));
+    }
+
+    #[test]
+    fn test_fuzzy_match_scores_consecutive_runs_higher() {
+        // "abc" is a consecutive run in "abcxyz" but scattered in
+        // "a-b-c-xyz"; the consecutive match should score higher.
+        let consecutive = fuzzy_match("abc", "abcxyz").unwrap();
+        let scattered = fuzzy_match("abc", "a-b-c-xyz").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn test_fuzzy_match_rewards_separator_and_camel_case_boundaries() {
+        // Matching right after a '_'/camelCase boundary scores higher
+        // than the same letters matched mid-word.
+        let after_separator = fuzzy_match("fb", "foo_bar").unwrap();
+        let mid_word = fuzzy_match("fb", "fabulous").unwrap();
+        assert!(after_separator > mid_word);
+
+        let camel_case = fuzzy_match("fb", "fooBar").unwrap();
+        assert!(camel_case > mid_word);
+    }
+
+    #[test]
+    fn test_filter_indices_ranks_best_match_first() {
+        let items = vec![
+            String::from("zzz"),
+            String::from("abc"),
+            String::from("azbzc"),
+        ];
+        assert_eq!(filter_indices(&items, ""), vec![0, 1, 2]);
+        assert_eq!(filter_indices(&items, "abc"), vec![1, 2]);
+    }
+}
@@ -0,0 +1,487 @@
+//! Declarative keybindings: translates raw `KeyEvent`s into mode-scoped
+//! `Action`s, with user overrides loaded from the config file layered on
+//! top of a built-in default keymap.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use std::fs;
+
+use crate::action::Action;
+use crate::{HelpType, InputMode};
+
+/// A key press reduced to the bits that matter for binding lookup: the
+/// code plus the modifier set, independent of event kind/repeat flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl From<KeyEvent> for KeyChord {
+    fn from(key: KeyEvent) -> KeyChord {
+        KeyChord {
+            code: key.code,
+            modifiers: key.modifiers,
+        }
+    }
+}
+
+impl KeyChord {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> KeyChord {
+        KeyChord { code, modifiers }
+    }
+
+    /// Parses a human-readable chord such as `"q"`, `"Ctrl-h"`, `"Esc"`.
+    /// Modifiers are joined with `-` or `+` and may appear in any order.
+    pub fn parse(spec: &str) -> Option<KeyChord> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut parts: Vec<&str> = spec.split(|c| c == '-' || c == '+').collect();
+        let key_part = parts.pop()?;
+
+        for modifier in parts {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "alt" => modifiers |= KeyModifiers::ALT,
+                "shift" => modifiers |= KeyModifiers::SHIFT,
+                _ => return None,
+            }
+        }
+
+        let code = match key_part.to_lowercase().as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            other => {
+                let mut chars = other.chars();
+                let c = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                KeyCode::Char(c)
+            }
+        };
+
+        Some(KeyChord::new(code, modifiers))
+    }
+}
+
+fn mode_section_name(mode: InputMode) -> &'static str {
+    match mode {
+        InputMode::Normal => "normal",
+        InputMode::Search => "search",
+        InputMode::Folder => "folder",
+        InputMode::Model => "model",
+        InputMode::Match => "match",
+        InputMode::Help => "help",
+        InputMode::Tenant => "tenant",
+    }
+}
+
+/// Resolves `(InputMode, KeyChord)` pairs to `Action`s. Starts out holding
+/// the built-in defaults; `load` overlays user bindings from the config
+/// file on top, so an unmapped key keeps doing whatever it does today.
+pub struct Keymap {
+    bindings: HashMap<(InputMode, KeyChord), Action>,
+}
+
+impl Keymap {
+    pub fn resolve(&self, mode: InputMode, key: KeyEvent) -> Action {
+        self.bindings
+            .get(&(mode, KeyChord::from(key)))
+            .cloned()
+            .unwrap_or(Action::Noop)
+    }
+
+    fn bind(&mut self, mode: InputMode, chord: KeyChord, action: Action) {
+        self.bindings.insert((mode, chord), action);
+    }
+
+    /// Lines of `(chord display, description)` bound in `mode`, used to
+    /// render that mode's help screen. `self.bindings` is a `HashMap`,
+    /// so its iteration order is randomized per process; sort the
+    /// result (by chord, then description) so the help screen reads
+    /// the same every run.
+    pub fn bindings_for(&self, mode: InputMode) -> Vec<(String, String)> {
+        let mut bindings: Vec<(String, String)> = self
+            .bindings
+            .iter()
+            .filter(|((m, _), _)| *m == mode)
+            .map(|((_, chord), action)| (display_chord(*chord), action.description()))
+            .filter(|(_, description)| !description.is_empty())
+            .collect();
+        bindings.sort();
+        bindings
+    }
+
+    /// Builds the keymap from built-in defaults, then overlays any
+    /// `[keybindings.<mode>]` sections found in `config_path`, or in a
+    /// sibling `ptui.conf` next to it if that exists instead.
+    pub fn load(config_path: &str) -> Keymap {
+        let mut keymap = Keymap::defaults();
+
+        let sibling_path = sibling_ptui_conf(config_path);
+        let source_path = if sibling_path
+            .as_ref()
+            .map(|p| std::path::Path::new(p).exists())
+            .unwrap_or(false)
+        {
+            sibling_path.unwrap()
+        } else {
+            config_path.to_string()
+        };
+
+        if let Ok(contents) = fs::read_to_string(&source_path) {
+            keymap.overlay_from_toml(&contents);
+        }
+
+        keymap
+    }
+
+    fn overlay_from_toml(&mut self, contents: &str) {
+        let parsed: toml::Value = match contents.parse() {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+
+        let modes = [
+            InputMode::Normal,
+            InputMode::Search,
+            InputMode::Folder,
+            InputMode::Model,
+            InputMode::Match,
+            InputMode::Help,
+            InputMode::Tenant,
+        ];
+
+        for mode in modes {
+            let section = parsed
+                .get("keybindings")
+                .and_then(|table| table.get(mode_section_name(mode)))
+                .and_then(|table| table.as_table());
+
+            let section = match section {
+                Some(section) => section,
+                None => continue,
+            };
+
+            for (key_spec, action_spec) in section {
+                let action_spec = match action_spec.as_str() {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                if let (Some(chord), Some(action)) =
+                    (KeyChord::parse(key_spec), Action::parse(action_spec))
+                {
+                    self.bind(mode, chord, action);
+                }
+            }
+        }
+    }
+
+    fn defaults() -> Keymap {
+        let mut keymap = Keymap {
+            bindings: HashMap::new(),
+        };
+
+        use InputMode::*;
+        use KeyModifiers as Mods;
+
+        keymap.bind(
+            Normal,
+            KeyChord::new(KeyCode::Char('q'), Mods::NONE),
+            Action::Quit,
+        );
+        keymap.bind(
+            Normal,
+            KeyChord::new(KeyCode::Char('f'), Mods::NONE),
+            Action::ChangeMode(Folder),
+        );
+        keymap.bind(
+            Normal,
+            KeyChord::new(KeyCode::Tab, Mods::NONE),
+            Action::ChangeMode(Folder),
+        );
+        keymap.bind(
+            Normal,
+            KeyChord::new(KeyCode::Char('s'), Mods::NONE),
+            Action::ChangeMode(Search),
+        );
+        keymap.bind(
+            Normal,
+            KeyChord::new(KeyCode::Char('m'), Mods::NONE),
+            Action::ChangeMode(Model),
+        );
+        keymap.bind(
+            Normal,
+            KeyChord::new(KeyCode::Char('c'), Mods::NONE),
+            Action::ChangeMode(Match),
+        );
+        keymap.bind(
+            Normal,
+            KeyChord::new(KeyCode::Char('h'), Mods::NONE),
+            Action::ShowHelp(HelpType::General),
+        );
+        keymap.bind(
+            Normal,
+            KeyChord::new(KeyCode::Char('t'), Mods::NONE),
+            Action::ChangeMode(Tenant),
+        );
+
+        keymap.bind(
+            Search,
+            KeyChord::new(KeyCode::Esc, Mods::NONE),
+            Action::Cancel,
+        );
+        keymap.bind(
+            Search,
+            KeyChord::new(KeyCode::Enter, Mods::NONE),
+            Action::ExecuteSearch,
+        );
+        keymap.bind(
+            Search,
+            KeyChord::new(KeyCode::Char('h'), Mods::CONTROL),
+            Action::ShowHelp(HelpType::Search),
+        );
+
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::Esc, Mods::NONE),
+            Action::Cancel,
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::Tab, Mods::NONE),
+            Action::ChangeMode(Model),
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::Char('h'), Mods::NONE),
+            Action::ShowHelp(HelpType::Folder),
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::Char('r'), Mods::NONE),
+            Action::ReloadFolders,
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::Up, Mods::NONE),
+            Action::PrevItem,
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::Down, Mods::NONE),
+            Action::NextItem,
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::Home, Mods::NONE),
+            Action::MoveFirst,
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::End, Mods::NONE),
+            Action::MoveLast,
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::PageUp, Mods::NONE),
+            Action::PageUp,
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::PageDown, Mods::NONE),
+            Action::PageDown,
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::Enter, Mods::NONE),
+            Action::SelectFolder,
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::Right, Mods::NONE),
+            Action::ExpandNode,
+        );
+        keymap.bind(
+            Folder,
+            KeyChord::new(KeyCode::Left, Mods::NONE),
+            Action::CollapseNode,
+        );
+
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Esc, Mods::NONE),
+            Action::Cancel,
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Tab, Mods::NONE),
+            Action::ChangeMode(Folder),
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Up, Mods::NONE),
+            Action::PrevItem,
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Down, Mods::NONE),
+            Action::NextItem,
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::PageUp, Mods::NONE),
+            Action::PageUp,
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::PageDown, Mods::NONE),
+            Action::PageDown,
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Enter, Mods::NONE),
+            Action::SelectModel,
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Char('r'), Mods::NONE),
+            Action::ReloadModels,
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Char('h'), Mods::NONE),
+            Action::ShowHelp(HelpType::Model),
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Char('s'), Mods::NONE),
+            Action::CycleSort,
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Char('f'), Mods::NONE),
+            Action::ToggleStatusFilter,
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Char('R'), Mods::NONE),
+            Action::ReverseSort,
+        );
+        keymap.bind(
+            Model,
+            KeyChord::new(KeyCode::Char('/'), Mods::NONE),
+            Action::ChangeMode(Search),
+        );
+
+        keymap.bind(
+            Match,
+            KeyChord::new(KeyCode::Esc, Mods::NONE),
+            Action::Cancel,
+        );
+        keymap.bind(
+            Match,
+            KeyChord::new(KeyCode::Char('h'), Mods::NONE),
+            Action::ShowHelp(HelpType::Match),
+        );
+
+        keymap.bind(
+            Tenant,
+            KeyChord::new(KeyCode::Esc, Mods::NONE),
+            Action::Cancel,
+        );
+        keymap.bind(
+            Tenant,
+            KeyChord::new(KeyCode::Char('h'), Mods::NONE),
+            Action::ShowHelp(HelpType::Tenant),
+        );
+        keymap.bind(
+            Tenant,
+            KeyChord::new(KeyCode::Up, Mods::NONE),
+            Action::PrevItem,
+        );
+        keymap.bind(
+            Tenant,
+            KeyChord::new(KeyCode::Down, Mods::NONE),
+            Action::NextItem,
+        );
+        keymap.bind(
+            Tenant,
+            KeyChord::new(KeyCode::Home, Mods::NONE),
+            Action::MoveFirst,
+        );
+        keymap.bind(
+            Tenant,
+            KeyChord::new(KeyCode::End, Mods::NONE),
+            Action::MoveLast,
+        );
+        keymap.bind(
+            Tenant,
+            KeyChord::new(KeyCode::PageUp, Mods::NONE),
+            Action::PageUp,
+        );
+        keymap.bind(
+            Tenant,
+            KeyChord::new(KeyCode::PageDown, Mods::NONE),
+            Action::PageDown,
+        );
+        keymap.bind(
+            Tenant,
+            KeyChord::new(KeyCode::Enter, Mods::NONE),
+            Action::SelectTenant,
+        );
+
+        keymap
+    }
+}
+
+fn sibling_ptui_conf(config_path: &str) -> Option<String> {
+    let path = std::path::Path::new(config_path);
+    let parent = path.parent()?;
+    Some(parent.join("ptui.conf").to_string_lossy().into_owned())
+}
+
+fn display_chord(chord: KeyChord) -> String {
+    let mut label = String::new();
+    if chord.modifiers.contains(KeyModifiers::CONTROL) {
+        label.push_str("Ctrl-");
+    }
+    if chord.modifiers.contains(KeyModifiers::ALT) {
+        label.push_str("Alt-");
+    }
+    if chord.modifiers.contains(KeyModifiers::SHIFT) {
+        label.push_str("Shift-");
+    }
+
+    label.push_str(&match chord.code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Esc => String::from("Esc"),
+        KeyCode::Enter => String::from("Enter"),
+        KeyCode::Tab => String::from("Tab"),
+        KeyCode::Backspace => String::from("Backspace"),
+        KeyCode::Delete => String::from("Delete"),
+        KeyCode::Home => String::from("Home"),
+        KeyCode::End => String::from("End"),
+        KeyCode::PageUp => String::from("PageUp"),
+        KeyCode::PageDown => String::from("PageDown"),
+        KeyCode::Up => String::from("Up Arrow"),
+        KeyCode::Down => String::from("Down Arrow"),
+        KeyCode::Left => String::from("Left Arrow"),
+        KeyCode::Right => String::from("Right Arrow"),
+        other => format!("{:?}", other),
+    });
+
+    format!("<{}>", label)
+}
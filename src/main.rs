@@ -1,6 +1,7 @@
 use crossterm::{
     event::{
-        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event, KeyCode, KeyModifiers,
     },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
@@ -16,75 +17,55 @@ use pcli::{
 };
 use std::{
     cell::{RefCell, RefMut},
+    collections::HashSet,
     env,
     error::Error,
     fmt,
+    time::Duration,
 };
 use tui::{
     backend::{Backend, CrosstermBackend},
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
-    style::{Color, Modifier, Style},
+    style::Style,
     text::{Span, Spans},
     widgets::{
         Block, BorderType, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table,
-        TableState, Wrap,
+        TableState,
     },
     Frame, Terminal,
 };
 use tui_logger::*;
-use tui_textarea::{self, Input, TextArea};
-
-const NORMAL_MODE_HELP: &str = r#"
-Normal Mode:
-
-<q>    Exit the program
-<t>    Select Physna tenant
-<f>    Switch to Folder mode
-<m>    Switch to Model mode
-
-Press any key to exit this help
-"#;
-
-const SEARCH_MODE_HELP: &str = r#"
-Search Mode:
-
-<Esc>          Exit to Normal mode
-<Backspace>    Delete the character left of the cursor
-<Left Arrow>   Move cursor left
-<Right Arrow>  Move cursor right
-<Home>         Go to beginning of line
-<End>          Go to end of line
-<Delete>       Delete character under cursor
-<Enter>        Execute search
-"#;
-
-const FOLDER_MODE_HELP: &str = r#"
-Folder Mode:
-
-<Esc>    Exit to Normal mode
-<r>      Reload the list of folders
-"#;
-
-const MODEL_MODE_HELP: &str = r#"
-Model Mode:
-
-<Esc>    Exit to Normal mode
-<r>      Reload the list of models
-"#;
-
-const MATCH_MODE_HELP: &str = r#"
-Match Mode:
-
-<Esc>    Exit to Normal mode
-<r>      Regenerate matches
-"#;
-
-const TENANT_MODE_HELP: &str = r#"
-Tenant Mode:
-
-<Esc>    Exit to Normal mode
-<r>      Regenerate matches
-"#;
+use unicode_segmentation::UnicodeSegmentation;
+
+mod action;
+mod cache;
+mod column;
+mod fuzzy;
+mod keymap;
+mod preview;
+mod script;
+mod scrollbar;
+mod text;
+mod theme;
+mod tree;
+mod worker;
+
+use action::Action;
+use cache::ModelCache;
+use column::{ColumnFilter, ColumnSorter, Columnar, SortColumn, SortDirection};
+use fuzzy::{filter_indices, Searchable};
+use keymap::Keymap;
+use preview::{render_half_blocks, PreviewCache, PreviewState};
+use script::ScriptEngine;
+use scrollbar::Scrollbar;
+use text::TextField;
+use theme::Theme;
+use tree::{build_tree, FolderNode};
+use worker::{Command, Worker, WorkerEvent};
+
+/// Frames of the spinner drawn in the status line while a background
+/// worker request (folders/models) is in flight.
+const SPINNER_FRAMES: [char; 8] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧'];
 
 #[derive(Debug)]
 pub enum PtuiError {
@@ -119,8 +100,8 @@ impl fmt::Display for PtuiError {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum InputMode {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum InputMode {
     Normal,
     Search,
     Folder,
@@ -130,8 +111,8 @@ enum InputMode {
     Tenant,
 }
 
-#[derive(Debug, Clone, Copy)]
-enum HelpType {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum HelpType {
     General,
     Search,
     Folder,
@@ -157,11 +138,14 @@ impl fmt::Display for InputMode {
 struct State<'a> {
     mode: InputMode,
     previous_mode: InputMode,
-    search_field: TextArea<'a>,
-    folder_list: StatefulList<Folder>,
+    search_field: TextField,
+    folder_list: StatefulList<FolderNode>,
+    all_folders: Vec<Folder>,
+    expanded_folders: HashSet<u32>,
     models_table: StatefulTable<'a, Model>,
     status_line: String,
-    help_text: String,
+    help_mode: InputMode,
+    help_bindings: Vec<(String, String)>,
     display_help: bool,
     display_tenants: bool,
     tenants: StatefulList<String>,
@@ -169,18 +153,44 @@ struct State<'a> {
     active_folder: Option<String>,
     configuration: ClientConfiguration,
     api: Option<service::Api>,
+    keymap: Keymap,
+    should_quit: bool,
+    cache: Option<ModelCache>,
+    config_path: String,
+    scripts: Option<ScriptEngine>,
+    theme: Theme,
+    worker: Worker,
+    /// Label of the request the background worker is currently running,
+    /// if any; drives the status-line spinner.
+    busy: Option<String>,
+    spinner_tick: usize,
+    previews: PreviewCache,
+    /// Inner row count the lists/table were last drawn with, recorded
+    /// by their `*_section` renderers so `PageUp`/`PageDown` can jump by
+    /// exactly one screenful.
+    folder_viewport: usize,
+    models_viewport: usize,
+    tenant_viewport: usize,
 }
 
 impl<'a> State<'a> {
-    pub fn new(configuration: ClientConfiguration) -> State<'static> {
+    pub fn new(
+        configuration: ClientConfiguration,
+        keymap: Keymap,
+        config_path: String,
+    ) -> State<'static> {
+        let theme = Theme::load(&config_path);
         State {
             mode: InputMode::Tenant,
             previous_mode: InputMode::Tenant,
-            search_field: TextArea::default(),
-            folder_list: StatefulList::default(), //with_items(vec![]),
+            search_field: TextField::new(),
+            folder_list: StatefulList::default(),
+            all_folders: vec![],
+            expanded_folders: HashSet::new(),
             models_table: StatefulTable::with_columns(vec!["Name", "Status", "UUID"]),
             status_line: String::new(),
-            help_text: String::default(),
+            help_mode: InputMode::Normal,
+            help_bindings: vec![],
             display_help: false,
             display_tenants: true,
             tenants: StatefulList::default(),
@@ -188,6 +198,19 @@ impl<'a> State<'a> {
             active_folder: None,
             configuration,
             api: None,
+            keymap,
+            should_quit: false,
+            cache: None,
+            config_path,
+            scripts: None,
+            theme,
+            worker: Worker::spawn(),
+            busy: None,
+            spinner_tick: 0,
+            previews: PreviewCache::default(),
+            folder_viewport: 0,
+            models_viewport: 0,
+            tenant_viewport: 0,
         }
     }
 
@@ -198,10 +221,61 @@ impl<'a> State<'a> {
             .clone()
             .for_each(|k| self.tenants.items.push(k.to_owned()));
         self.tenants.items.sort();
-
-        self.search_field.set_cursor_line_style(Style::default());
+        self.tenants.reset_filter();
 
         self.models_table.clear();
+
+        self.scripts = ScriptEngine::load(&self.config_path);
+    }
+
+    /// Runs the script command registered under `name`, syncing the
+    /// current tenant/folder in first and applying anything the script
+    /// requested to change once it returns.
+    fn run_script(&mut self, name: &str) {
+        match &self.scripts {
+            Some(scripts) => {
+                scripts.sync(self.active_tenant.as_deref(), self.active_folder.as_deref());
+                scripts.run_command(name);
+            }
+            None => {
+                warn!("No init.lua is loaded; \"{}\" has no script bound", name);
+                return;
+            }
+        }
+
+        self.apply_script_requests();
+    }
+
+    /// Applies a tenant/folder change requested by the last script
+    /// command or hook that ran, if any, the same way the equivalent
+    /// user-driven action (`select_tenant`/`select_folder`) would.
+    fn apply_script_requests(&mut self) {
+        let (requested_tenant, requested_folder) = match &self.scripts {
+            Some(scripts) => scripts.take_requests(),
+            None => return,
+        };
+
+        if let Some(tenant) = requested_tenant {
+            self.active_tenant = Some(tenant.clone());
+            match self.initialize_service() {
+                Ok(()) => self.reload_folders(),
+                Err(e) => warn!(
+                    "Script requested tenant \"{}\" but it failed: {}",
+                    tenant, e
+                ),
+            }
+        }
+
+        if let Some(folder) = requested_folder {
+            match self.all_folders.iter().find(|f| f.name == folder) {
+                Some(f) => {
+                    let id = f.id;
+                    self.active_folder = Some(folder);
+                    self.load_models_for_folder(id);
+                }
+                None => warn!("Script requested folder \"{}\" but it wasn't found", folder),
+            }
+        }
     }
 
     pub fn initialize_service(&mut self) -> Result<(), PtuiError> {
@@ -236,15 +310,57 @@ impl<'a> State<'a> {
             active_tenant.clone()
         );
         self.api = api;
+
+        if let Some(api) = &self.api {
+            self.worker.send(Command::SetApi(api.clone()));
+        }
+
+        if self.cache.is_none() {
+            match ModelCache::open(&cache::default_cache_path()) {
+                Ok(cache) => self.cache = Some(cache),
+                Err(e) => warn!(
+                    "Failed to open the model cache, continuing without it: {}",
+                    e
+                ),
+            }
+        }
+
         Ok(())
     }
 
     pub fn clear_folders(&mut self) {
+        self.all_folders.clear();
+        self.expanded_folders.clear();
         self.folder_list.items.clear();
+        self.folder_list.reset_filter();
     }
 
     pub fn add_folder(&mut self, folder: Folder) {
-        self.folder_list.items.push(folder.clone());
+        self.all_folders.push(folder);
+    }
+
+    /// Re-flattens `all_folders` into `folder_list`, honoring the
+    /// current `expanded_folders`, and tries to keep the same folder
+    /// selected across the rebuild (expand/collapse and reload both
+    /// shuffle row positions around).
+    fn rebuild_folder_tree(&mut self) {
+        let selected_id = self.folder_list.selected().map(|node| node.folder.id);
+
+        self.folder_list.items = build_tree(&self.all_folders, &self.expanded_folders);
+        self.folder_list.reset_filter();
+        let query = self.search_field.text();
+        self.folder_list.apply_filter(&query);
+
+        if let Some(id) = selected_id {
+            if let Some(position) = self
+                .folder_list
+                .filtered_indices
+                .iter()
+                .position(|&index| self.folder_list.items[index].folder.id == id)
+            {
+                self.folder_list.state.select(Some(position));
+            }
+        }
     }
 
     pub fn change_mode(&mut self, mode: InputMode) {
@@ -254,6 +370,12 @@ impl<'a> State<'a> {
 
         debug!("Changed mode from {} to {}", self.previous_mode, self.mode);
 
+        if let Some(scripts) = &self.scripts {
+            scripts.sync(self.active_tenant.as_deref(), self.active_folder.as_deref());
+            scripts.on_mode_change(&self.mode.to_string());
+        }
+        self.apply_script_requests();
+
         match self.mode {
             InputMode::Normal => {
                 self.status_line = String::from("Press <h> for help or <q> to exit");
@@ -289,60 +411,466 @@ impl<'a> State<'a> {
     }
 
     pub fn set_help(&mut self, help_type: HelpType) {
-        match help_type {
-            HelpType::General => {
-                self.help_text = String::from(NORMAL_MODE_HELP);
-                self.display_help = true;
+        let mode = match help_type {
+            HelpType::General => InputMode::Normal,
+            HelpType::Search => InputMode::Search,
+            HelpType::Folder => InputMode::Folder,
+            HelpType::Model => InputMode::Model,
+            HelpType::Match => InputMode::Match,
+            HelpType::Tenant => InputMode::Tenant,
+        };
+        self.help_mode = mode;
+        self.help_bindings = self.keymap.bindings_for(mode);
+        self.help_bindings.sort();
+        self.display_help = true;
+    }
+
+    pub fn hide_help(&mut self) {
+        self.display_help = false;
+    }
+
+    pub fn show_help(&self) -> bool {
+        self.display_help
+    }
+
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+
+    /// Performs the state mutation for `action`. `run_app` resolves each
+    /// bound key to an `Action` and hands it here, so the transitions can
+    /// be driven by anything capable of producing the same enum. Text
+    /// editing in Search mode, bracketed pastes, and dismissing the help
+    /// overlay are handled by `run_app` directly and never pass through
+    /// here.
+    pub fn apply(&mut self, action: Action) -> Result<(), PtuiError> {
+        match action {
+            Action::Quit => self.should_quit = true,
+            Action::ChangeMode(InputMode::Tenant) => {
+                self.display_tenants = true;
+                self.change_mode(InputMode::Tenant);
             }
-            HelpType::Search => {
-                self.help_text = String::from(SEARCH_MODE_HELP);
-                self.display_help = true;
+            Action::ChangeMode(mode) => self.change_mode(mode),
+            Action::ShowHelp(help_type) => {
+                self.set_help(help_type);
+                self.change_mode(InputMode::Help);
             }
-            HelpType::Folder => {
-                self.help_text = String::from(FOLDER_MODE_HELP);
-                self.display_help = true;
+            Action::Cancel => self.cancel(),
+            Action::NextItem => self.next_item(),
+            Action::PrevItem => self.prev_item(),
+            Action::MoveFirst => self.move_first(),
+            Action::MoveLast => self.move_last(),
+            Action::PageUp => self.page_up(),
+            Action::PageDown => self.page_down(),
+            Action::SelectFolder => self.select_folder(),
+            Action::SelectModel => self.select_model(),
+            Action::SelectTenant => self.select_tenant(),
+            Action::ExecuteSearch => self.execute_search(),
+            Action::ReloadFolders => self.reload_folders(),
+            Action::ReloadModels => self.reload_models(),
+            Action::CycleSort => self.cycle_sort(),
+            Action::ReverseSort => self.reverse_sort(),
+            Action::ToggleStatusFilter => self.toggle_status_filter(),
+            Action::ExpandNode => self.expand_selected_folder(),
+            Action::CollapseNode => self.collapse_selected_folder(),
+            Action::RunScript(name) => self.run_script(&name),
+            Action::Noop => {}
+        }
+
+        Ok(())
+    }
+
+    fn cancel(&mut self) {
+        match self.mode {
+            InputMode::Tenant => {
+                self.display_tenants = false;
+                self.change_mode(InputMode::Normal);
             }
-            HelpType::Model => {
-                self.help_text = String::from(MODEL_MODE_HELP);
-                self.display_help = true;
+            _ => self.change_mode(InputMode::Normal),
+        }
+    }
+
+    fn next_item(&mut self) {
+        match self.mode {
+            InputMode::Folder => self.folder_list.next(),
+            InputMode::Model => self.models_table.next(),
+            InputMode::Tenant => self.tenants.next(),
+            _ => {}
+        }
+    }
+
+    fn prev_item(&mut self) {
+        match self.mode {
+            InputMode::Folder => self.folder_list.previous(),
+            InputMode::Model => self.models_table.previous(),
+            InputMode::Tenant => self.tenants.previous(),
+            _ => {}
+        }
+    }
+
+    fn move_first(&mut self) {
+        match self.mode {
+            InputMode::Folder => self.folder_list.first(),
+            InputMode::Tenant => self.tenants.first(),
+            _ => {}
+        }
+    }
+
+    fn move_last(&mut self) {
+        match self.mode {
+            InputMode::Folder => self.folder_list.last(),
+            InputMode::Tenant => self.tenants.last(),
+            _ => {}
+        }
+    }
+
+    fn page_up(&mut self) {
+        match self.mode {
+            InputMode::Folder => self.folder_list.page_up(self.folder_viewport),
+            InputMode::Model => self.models_table.page_up(self.models_viewport),
+            InputMode::Tenant => self.tenants.page_up(self.tenant_viewport),
+            _ => {}
+        }
+    }
+
+    fn page_down(&mut self) {
+        match self.mode {
+            InputMode::Folder => self.folder_list.page_down(self.folder_viewport),
+            InputMode::Model => self.models_table.page_down(self.models_viewport),
+            InputMode::Tenant => self.tenants.page_down(self.tenant_viewport),
+            _ => {}
+        }
+    }
+
+    fn execute_search(&mut self) {
+        let text = self.search_field.text();
+        debug!("Search for \"{}\"", text);
+
+        if let Some(scripts) = &self.scripts {
+            scripts.sync(self.active_tenant.as_deref(), self.active_folder.as_deref());
+            scripts.on_search(&text);
+        }
+        self.apply_script_requests();
+    }
+
+    /// Re-scores `folder_list` and `models_table` against the current
+    /// search text. Called on every keystroke in Search mode, so the
+    /// visible lists narrow down incrementally as the user types.
+    fn refilter(&mut self) {
+        let query = self.search_field.text();
+        self.folder_list.apply_filter(&query);
+        self.models_table.apply_filter(&query);
+    }
+
+    fn cycle_sort(&mut self) {
+        if let InputMode::Model = self.mode {
+            let query = self.search_field.text();
+            self.models_table.cycle_sort(&query);
+        }
+    }
+
+    fn reverse_sort(&mut self) {
+        if let InputMode::Model = self.mode {
+            let query = self.search_field.text();
+            self.models_table.reverse_sort(&query);
+        }
+    }
+
+    fn toggle_status_filter(&mut self) {
+        if let InputMode::Model = self.mode {
+            let query = self.search_field.text();
+            self.models_table.toggle_status_filter(&query);
+        }
+    }
+
+    fn select_folder(&mut self) {
+        match self.folder_list.selected() {
+            Some(node) => {
+                let name = node.folder.name.to_owned();
+                let id = node.folder.id;
+                self.active_folder = Some(name.clone());
+                debug!("Selected folder [{}] \"{}\"", id, name);
+                self.load_models_for_folder(id);
+
+                if let Some(scripts) = &self.scripts {
+                    scripts.sync(self.active_tenant.as_deref(), Some(&name));
+                    scripts.on_folder_selected(&name);
+                }
+                self.apply_script_requests();
             }
-            HelpType::Match => {
-                self.help_text = String::from(MATCH_MODE_HELP);
-                self.display_help = true;
+            None => {
+                self.active_folder = None;
+                self.models_table.clear();
+                warn!("No folder selected");
             }
-            HelpType::Tenant => {
-                self.help_text = String::from(TENANT_MODE_HELP);
-                self.display_help = true;
+        }
+    }
+
+    /// Expands the selected folder's children into the tree, if it has
+    /// any and isn't already expanded.
+    fn expand_selected_folder(&mut self) {
+        if let InputMode::Folder = self.mode {
+            if let Some(node) = self.folder_list.selected() {
+                if node.has_children && !node.expanded {
+                    self.expanded_folders.insert(node.folder.id);
+                    self.rebuild_folder_tree();
+                }
             }
         }
     }
 
-    pub fn hide_help(&mut self) {
-        self.display_help = false;
+    /// Collapses the selected folder's children out of the tree, if
+    /// it's currently expanded.
+    fn collapse_selected_folder(&mut self) {
+        if let InputMode::Folder = self.mode {
+            if let Some(node) = self.folder_list.selected() {
+                if node.expanded {
+                    self.expanded_folders.remove(&node.folder.id);
+                    self.rebuild_folder_tree();
+                }
+            }
+        }
     }
 
-    pub fn show_help(&self) -> bool {
-        self.display_help
+    fn select_model(&mut self) {
+        match self.models_table.selected() {
+            Some(selected_row) => {
+                debug!("Selected model \"{}\"", selected_row.uuid);
+            }
+            None => warn!("No model selected"),
+        }
+    }
+
+    fn select_tenant(&mut self) {
+        match self.tenants.selected() {
+            Some(selected_item) => {
+                let active_tenant = selected_item.to_owned();
+                self.active_tenant = Some(active_tenant.clone());
+                info!("Selected tenant \"{}\"", active_tenant);
+
+                match self.initialize_service() {
+                    Ok(()) => {
+                        debug!("Connected to the Physna service");
+                    }
+                    Err(e) => {
+                        error!("Unable to connect to Physna, because of: {}", e)
+                    }
+                }
+
+                self.reload_folders();
+
+                self.display_tenants = false;
+                self.change_mode(InputMode::Normal);
+            }
+            None => {
+                self.active_tenant = None;
+                warn!("No tenant selected");
+            }
+        }
+    }
+
+    /// Asks the background worker to fetch the list of folders and
+    /// returns immediately; the result is picked up by `poll_worker`
+    /// once it arrives.
+    fn reload_folders(&mut self) {
+        match &self.api {
+            Some(_) => {
+                self.busy = Some(String::from("Loading folders"));
+                self.worker.send(Command::LoadFolders);
+            }
+            None => {
+                warn!("No connection with Physna");
+            }
+        }
+    }
+
+    fn reload_models(&mut self) {
+        match &self.active_folder {
+            Some(_) => {
+                if let Some(node) = self.folder_list.selected() {
+                    let id = node.folder.id;
+                    self.load_models_for_folder_impl(id, true);
+                    return;
+                }
+                warn!("No folder selected");
+            }
+            None => warn!("No folder selected"),
+        }
+    }
+
+    fn load_models_for_folder(&mut self, folder_id: u32) {
+        self.load_models_for_folder_impl(folder_id, false);
+    }
+
+    /// Populates `models_table` for `folder_id`, serving from the cache
+    /// on a fresh-enough hit unless `force_refresh` is set (as the `r`
+    /// Reload action does), in which case the API is always called and
+    /// the cache entry is rewritten.
+    fn load_models_for_folder_impl(&mut self, folder_id: u32, force_refresh: bool) {
+        if !force_refresh {
+            if let (Some(cache), Some(tenant)) = (&self.cache, self.active_tenant.clone()) {
+                if let Some(models) = cache.get(&tenant, folder_id) {
+                    debug!(
+                        "Loaded {} model(s) for folder {} from the cache",
+                        models.len(),
+                        folder_id
+                    );
+                    self.populate_models_table(models);
+                    return;
+                }
+            }
+        }
+
+        match &self.api {
+            Some(_) => {
+                debug!("Reading the list of models for folder {}...", folder_id);
+                self.busy = Some(format!("Loading models for folder {}", folder_id));
+                self.worker.send(Command::LoadModels(folder_id));
+            }
+            None => {
+                self.active_folder = None;
+                self.models_table.clear();
+            }
+        }
+    }
+
+    /// Drains results the background worker has finished since the last
+    /// frame and applies them to state. Only `Folders`/`Models` clear
+    /// `busy` (the requests that actually set it); a `Thumbnail` landing
+    /// while one of those is still in flight must leave the spinner up.
+    /// Models are only applied if the folder they were fetched for is
+    /// still the one selected, so a stale response from a folder the
+    /// user has since navigated away from can't clobber the table.
+    fn poll_worker(&mut self) {
+        while let Some(event) = self.worker.poll() {
+            match event {
+                WorkerEvent::Folders(result) => {
+                    self.busy = None;
+                    match result {
+                        Ok(mut folders) => {
+                            self.clear_folders();
+                            folders.sort();
+                            folders.iter().for_each(|f| {
+                                self.add_folder(f.clone());
+                            });
+                            self.rebuild_folder_tree();
+                            debug!("List of folders ready");
+                        }
+                        Err(e) => {
+                            error!("Failed to read the list of folders: {}", e);
+                        }
+                    }
+                }
+                WorkerEvent::Models { folder_id, result } => {
+                    self.busy = None;
+                    let still_selected = self
+                        .folder_list
+                        .selected()
+                        .map(|node| node.folder.id == folder_id)
+                        .unwrap_or(false);
+
+                    match result {
+                        Ok(models) => {
+                            debug!("Found {} model(s)", models.len());
+
+                            if let (Some(cache), Some(tenant)) = (&self.cache, &self.active_tenant)
+                            {
+                                if let Err(e) = cache.put(tenant, folder_id, &models) {
+                                    warn!("Failed to update the model cache: {}", e);
+                                }
+                            }
+
+                            if still_selected {
+                                self.populate_models_table(models);
+                            }
+                        }
+                        Err(e) => error!("Error reading models: {}", e),
+                    }
+                }
+                WorkerEvent::Thumbnail { uuid, result } => {
+                    if let Err(e) = &result {
+                        warn!("Failed to load thumbnail for {}: {}", uuid, e);
+                    }
+                    self.previews.store(&uuid, result);
+                }
+            }
+        }
+    }
+
+    /// Kicks off a thumbnail fetch for the selected model the first
+    /// time its UUID is seen, so `preview_section` has something to
+    /// show (a placeholder until then). Checked once per frame, the
+    /// same as `poll_worker`.
+    fn ensure_preview_requested(&mut self) {
+        if self.mode != InputMode::Model {
+            return;
+        }
+
+        if let Some(model) = self.models_table.selected() {
+            let uuid = model.uuid.to_string();
+            if self.previews.needs_fetch(&uuid) {
+                self.previews.mark_loading(&uuid);
+                self.worker.send(Command::LoadThumbnail(uuid));
+            }
+        }
+    }
+
+    fn populate_models_table(&mut self, models: Vec<Model>) {
+        self.models_table.clear();
+        models.into_iter().for_each(|model| {
+            self.models_table.add_row(model);
+        });
+        let query = self.search_field.text();
+        self.models_table.apply_filter(&query);
+    }
+}
+
+impl Searchable for Model {
+    fn search_text(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Columnar for Model {
+    fn column_value(&self, column: SortColumn) -> String {
+        match column {
+            SortColumn::Name => self.name.clone(),
+            SortColumn::Status => self.state.clone(),
+            SortColumn::Uuid => self.uuid.to_string(),
+        }
     }
 }
 
 struct StatefulList<T> {
     state: ListState,
     items: Vec<T>,
+    filtered_indices: Vec<usize>,
 }
 
 impl<T> StatefulList<T> {
     fn with_items(items: Vec<T>) -> StatefulList<T> {
+        let filtered_indices = (0..items.len()).collect();
         StatefulList {
             state: ListState::default(),
             items,
+            filtered_indices,
         }
     }
 
+    /// Drops any active filter, so every item is visible again.
+    fn reset_filter(&mut self) {
+        self.filtered_indices = (0..self.items.len()).collect();
+    }
+
     fn next(&mut self) {
+        let len = self.filtered_indices.len();
+        if len == 0 {
+            self.state.select(None);
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
-                if i >= self.items.len() - 1 {
+                if i >= len - 1 {
                     0
                 } else {
                     i + 1
@@ -354,10 +882,15 @@ impl<T> StatefulList<T> {
     }
 
     fn previous(&mut self) {
+        let len = self.filtered_indices.len();
+        if len == 0 {
+            self.state.select(None);
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.items.len() - 1
+                    len - 1
                 } else {
                     i - 1
                 }
@@ -368,14 +901,73 @@ impl<T> StatefulList<T> {
     }
 
     fn first(&mut self) {
-        self.state.select(Some(0));
+        if self.filtered_indices.is_empty() {
+            self.state.select(None);
+        } else {
+            self.state.select(Some(0));
+        }
     }
 
     fn last(&mut self) {
-        if self.items.is_empty() {
-            self.first();
+        if self.filtered_indices.is_empty() {
+            self.state.select(None);
+        } else {
+            self.state.select(Some(self.filtered_indices.len() - 1));
+        }
+    }
+
+    /// Jumps up by `page_size` rows (at least one), clamped to the
+    /// first item.
+    fn page_up(&mut self, page_size: usize) {
+        if self.filtered_indices.is_empty() {
+            self.state.select(None);
+            return;
+        }
+        let i = self.state.selected().unwrap_or(0);
+        self.state.select(Some(i.saturating_sub(page_size.max(1))));
+    }
+
+    /// Jumps down by `page_size` rows (at least one), clamped to the
+    /// last item.
+    fn page_down(&mut self, page_size: usize) {
+        let len = self.filtered_indices.len();
+        if len == 0 {
+            self.state.select(None);
+            return;
+        }
+        let i = self.state.selected().unwrap_or(0);
+        self.state.select(Some((i + page_size.max(1)).min(len - 1)));
+    }
+
+    /// The currently selected item, resolved through the active filter.
+    fn selected(&self) -> Option<&T> {
+        self.state
+            .selected()
+            .and_then(|position| self.filtered_indices.get(position))
+            .and_then(|&index| self.items.get(index))
+    }
+
+    /// The items currently visible under the active filter, in display
+    /// order; what the list widget should render.
+    fn visible_items(&self) -> Vec<&T> {
+        self.filtered_indices
+            .iter()
+            .filter_map(|&index| self.items.get(index))
+            .collect()
+    }
+}
+
+impl<T: Searchable> StatefulList<T> {
+    /// Re-ranks `items` against `query` and narrows `filtered_indices` to
+    /// the matches, best score first; an empty query shows everything.
+    fn apply_filter(&mut self, query: &str) {
+        self.filtered_indices = filter_indices(&self.items, query);
+        if self.filtered_indices.is_empty() {
+            self.state.select(None);
         } else {
-            self.state.select(Some(self.items.len() - 1));
+            let position = self.state.selected().unwrap_or(0);
+            self.state
+                .select(Some(position.min(self.filtered_indices.len() - 1)));
         }
     }
 }
@@ -390,6 +982,9 @@ struct StatefulTable<'a, T> {
     state: TableState,
     columns: Vec<&'a str>,
     rows: Vec<T>,
+    filtered_indices: Vec<usize>,
+    sorters: Vec<ColumnSorter>,
+    filters: Vec<ColumnFilter>,
 }
 
 impl<'a, T> StatefulTable<'a, T> {
@@ -398,21 +993,35 @@ impl<'a, T> StatefulTable<'a, T> {
             state: TableState::default(),
             columns,
             rows: vec![],
+            filtered_indices: vec![],
+            sorters: vec![],
+            filters: vec![],
         }
     }
 
     fn add_row(&mut self, row: T) {
         self.rows.push(row);
+        self.reset_filter();
     }
 
     fn clear(&mut self) {
         self.rows.clear();
+        self.filtered_indices.clear();
+    }
+
+    fn reset_filter(&mut self) {
+        self.filtered_indices = (0..self.rows.len()).collect();
     }
 
     pub fn next(&mut self) {
+        let len = self.filtered_indices.len();
+        if len == 0 {
+            self.state.select(None);
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
-                if i >= self.rows.len() - 1 {
+                if i >= len - 1 {
                     0
                 } else {
                     i + 1
@@ -424,10 +1033,15 @@ impl<'a, T> StatefulTable<'a, T> {
     }
 
     pub fn previous(&mut self) {
+        let len = self.filtered_indices.len();
+        if len == 0 {
+            self.state.select(None);
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.rows.len() - 1
+                    len - 1
                 } else {
                     i - 1
                 }
@@ -436,6 +1050,142 @@ impl<'a, T> StatefulTable<'a, T> {
         };
         self.state.select(Some(i));
     }
+
+    /// Jumps up by `page_size` rows (at least one), clamped to the
+    /// first row.
+    pub fn page_up(&mut self, page_size: usize) {
+        if self.filtered_indices.is_empty() {
+            self.state.select(None);
+            return;
+        }
+        let i = self.state.selected().unwrap_or(0);
+        self.state.select(Some(i.saturating_sub(page_size.max(1))));
+    }
+
+    /// Jumps down by `page_size` rows (at least one), clamped to the
+    /// last row.
+    pub fn page_down(&mut self, page_size: usize) {
+        let len = self.filtered_indices.len();
+        if len == 0 {
+            self.state.select(None);
+            return;
+        }
+        let i = self.state.selected().unwrap_or(0);
+        self.state.select(Some((i + page_size.max(1)).min(len - 1)));
+    }
+
+    /// The currently selected row, resolved through the active filter.
+    fn selected(&self) -> Option<&T> {
+        self.state
+            .selected()
+            .and_then(|position| self.filtered_indices.get(position))
+            .and_then(|&index| self.rows.get(index))
+    }
+
+    /// The rows currently visible under the active filter, in display
+    /// order; what the table widget should render.
+    fn visible_rows(&self) -> Vec<&T> {
+        self.filtered_indices
+            .iter()
+            .filter_map(|&index| self.rows.get(index))
+            .collect()
+    }
+}
+
+impl<'a, T: Searchable + Columnar> StatefulTable<'a, T> {
+    /// Re-ranks `rows` against `query` (best fuzzy score first), drops
+    /// rows that don't pass every active `filters` predicate, then
+    /// applies the active column `sorters` on top, if any.
+    fn apply_filter(&mut self, query: &str) {
+        let mut indices = filter_indices(&self.rows, query);
+        indices.retain(|&index| {
+            self.filters
+                .iter()
+                .all(|filter| filter.matches(&self.rows[index]))
+        });
+
+        if let Some(sorter) = self.sorters.last() {
+            indices.sort_by(|&a, &b| {
+                let ordering = self.rows[a]
+                    .column_value(sorter.column)
+                    .cmp(&self.rows[b].column_value(sorter.column));
+                match sorter.direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+
+        self.filtered_indices = indices;
+        if self.filtered_indices.is_empty() {
+            self.state.select(None);
+        } else {
+            let position = self.state.selected().unwrap_or(0);
+            self.state
+                .select(Some(position.min(self.filtered_indices.len() - 1)));
+        }
+    }
+
+    /// Advances the active column sorter and re-derives the view,
+    /// keeping the same row selected by UUID even as it moves.
+    fn cycle_sort(&mut self, query: &str) {
+        let selected_uuid = self
+            .selected()
+            .map(|row| row.column_value(SortColumn::Uuid));
+        self.sorters = match ColumnSorter::cycle(self.sorters.last().copied()) {
+            Some(sorter) => vec![sorter],
+            None => vec![],
+        };
+        self.apply_filter(query);
+        self.reselect_by_uuid(selected_uuid);
+    }
+
+    /// Flips the active sorter's direction (defaulting to Name
+    /// descending if there isn't one yet), keeping the same row
+    /// selected by UUID.
+    fn reverse_sort(&mut self, query: &str) {
+        let selected_uuid = self
+            .selected()
+            .map(|row| row.column_value(SortColumn::Uuid));
+        self.sorters = vec![match self.sorters.last() {
+            Some(sorter) => sorter.reversed(),
+            None => ColumnSorter {
+                column: SortColumn::Name,
+                direction: SortDirection::Descending,
+            },
+        }];
+        self.apply_filter(query);
+        self.reselect_by_uuid(selected_uuid);
+    }
+
+    /// Re-selects the row whose UUID column matches `uuid`, if it's
+    /// still present in the filtered view.
+    fn reselect_by_uuid(&mut self, uuid: Option<String>) {
+        if let Some(uuid) = uuid {
+            if let Some(position) = self
+                .filtered_indices
+                .iter()
+                .position(|&index| self.rows[index].column_value(SortColumn::Uuid) == uuid)
+            {
+                self.state.select(Some(position));
+            }
+        }
+    }
+
+    /// Toggles a filter restricting the table to rows whose Status
+    /// matches the currently selected row, so a single key press can
+    /// narrow the list down to "more like this one".
+    fn toggle_status_filter(&mut self, query: &str) {
+        if self.filters.is_empty() {
+            if let Some(selected) = self.selected() {
+                let status = selected.column_value(SortColumn::Status);
+                self.filters.push(ColumnFilter::StatusEquals(status));
+            }
+        } else {
+            self.filters.clear();
+        }
+        self.apply_filter(query);
+    }
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -467,8 +1217,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let mut default_configuration_file_path = home_directory;
     default_configuration_file_path.push_str("/.pcli.conf");
 
-    let configuration =
-        pcli::configuration::initialize(&String::from(default_configuration_file_path));
+    let configuration = pcli::configuration::initialize(&default_configuration_file_path);
     let configuration = match configuration {
         Ok(configuration) => configuration,
         Err(e) => {
@@ -483,11 +1232,22 @@ fn main() -> Result<(), Box<dyn Error>> {
     tui_logger::init_logger(level_filter).unwrap();
     tui_logger::set_default_level(level_filter);
 
+    let keymap = Keymap::load(&default_configuration_file_path);
+
     // Prepare the state
-    let state = RefCell::new(State::new(configuration));
+    let state = RefCell::new(State::new(
+        configuration,
+        keymap,
+        default_configuration_file_path.clone(),
+    ));
 
     enable_raw_mode()?;
-    execute!(std::io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        std::io::stdout(),
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(std::io::stdout());
     let mut terminal = Terminal::new(backend)?;
 
@@ -497,7 +1257,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
 
     if let Err(e) = result {
@@ -517,392 +1278,103 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, state: RefCell<State>) -> Res
             Err(_) => return Err(PtuiError::DisplayError),
         }
 
+        state.poll_worker();
+        state.ensure_preview_requested();
+
+        let has_event = match event::poll(Duration::from_millis(100)) {
+            Ok(has_event) => has_event,
+            Err(_) => return Err(PtuiError::InputError),
+        };
+
+        if !has_event {
+            if state.busy.is_some() {
+                state.spinner_tick = state.spinner_tick.wrapping_add(1);
+            }
+            continue;
+        }
+
         let event = match event::read() {
             Ok(event) => event,
             Err(_) => return Err(PtuiError::InputError),
         };
 
-        match state.mode {
-            InputMode::Normal => match event {
-                Event::Key(key) => match key {
-                    KeyEvent {
-                        code: KeyCode::Char('q'),
-                        ..
-                    } => {
-                        return Ok(());
-                    }
-                    KeyEvent {
-                        code: KeyCode::Char('f'),
-                        ..
-                    }
-                    | KeyEvent {
-                        code: KeyCode::Tab, ..
-                    } => {
-                        state.change_mode(InputMode::Folder);
-                    }
-                    KeyEvent {
-                        code: KeyCode::Char('s'),
-                        ..
-                    } => {
-                        state.change_mode(InputMode::Search);
-                    }
-                    KeyEvent {
-                        code: KeyCode::Char('m'),
-                        ..
-                    } => state.change_mode(InputMode::Model),
-                    KeyEvent {
-                        code: KeyCode::Char('c'),
-                        ..
-                    } => state.change_mode(InputMode::Match),
-                    KeyEvent {
-                        code: KeyCode::Char('h'),
-                        ..
-                    } => {
-                        state.set_help(HelpType::General);
-                        state.change_mode(InputMode::Help);
-                    }
-                    KeyEvent {
-                        code: KeyCode::Char('t'),
-                        ..
-                    } => {
-                        state.display_tenants = true;
-                        state.change_mode(InputMode::Tenant);
-                    }
-                    _ => {
-                        warn!("Unsupported key binding. Press <h> for help");
-                        state.status_line = String::from("Press <h> for help or <q> to exit");
-                    }
-                },
-                _ => {}
-            },
-            InputMode::Search => match event {
-                Event::Key(key) => match key {
-                    KeyEvent {
-                        code: KeyCode::Esc, ..
-                    } => state.change_mode(InputMode::Normal),
-                    KeyEvent {
-                        code: KeyCode::Enter,
-                        ..
-                    } => {
-                        let text = state.search_field.lines()[0].clone();
-                        debug!("Search for \"{}\"", text);
-                    }
-                    KeyEvent {
-                        code: KeyCode::Char('h'),
-                        modifiers: KeyModifiers::CONTROL,
-                        ..
-                    } => {
-                        state.set_help(HelpType::Search);
-                        state.change_mode(InputMode::Help);
-                    }
-                    _ => {
-                        let input: Input = Input {
-                            ctrl: key.modifiers.contains(KeyModifiers::CONTROL),
-                            alt: key.modifiers.contains(KeyModifiers::ALT),
-                            key: match key.code {
-                                KeyCode::Char(c) => tui_textarea::Key::Char(c),
-                                KeyCode::Backspace => tui_textarea::Key::Backspace,
-                                KeyCode::Enter => tui_textarea::Key::Enter,
-                                KeyCode::Left => tui_textarea::Key::Left,
-                                KeyCode::Right => tui_textarea::Key::Right,
-                                KeyCode::Up => tui_textarea::Key::Up,
-                                KeyCode::Down => tui_textarea::Key::Down,
-                                KeyCode::Tab => tui_textarea::Key::Tab,
-                                KeyCode::Delete => tui_textarea::Key::Delete,
-                                KeyCode::Home => tui_textarea::Key::Home,
-                                KeyCode::End => tui_textarea::Key::End,
-                                KeyCode::PageUp => tui_textarea::Key::PageUp,
-                                KeyCode::PageDown => tui_textarea::Key::PageDown,
-                                KeyCode::Esc => tui_textarea::Key::Esc,
-                                KeyCode::F(x) => tui_textarea::Key::F(x),
-                                _ => tui_textarea::Key::Null,
-                            },
-                        };
-                        state.search_field.input(input);
-                    }
-                },
-                _ => {}
-            },
-            InputMode::Folder => match event {
-                Event::Key(key) => match key {
-                    KeyEvent {
-                        code: KeyCode::Esc, ..
-                    } => state.change_mode(InputMode::Normal),
-                    KeyEvent {
-                        code: KeyCode::Tab, ..
-                    } => state.change_mode(InputMode::Model),
-                    KeyEvent {
-                        code: KeyCode::Char('h'),
-                        ..
-                    } => {
-                        state.set_help(HelpType::Folder);
-                        state.change_mode(InputMode::Help);
-                    }
-                    KeyEvent {
-                        code: KeyCode::Up, ..
-                    } => {
-                        state.folder_list.previous();
-                    }
-                    KeyEvent {
-                        code: KeyCode::Down,
-                        ..
-                    } => {
-                        state.folder_list.next();
-                    }
-                    KeyEvent {
-                        code: KeyCode::Home,
-                        ..
-                    } => {
-                        state.folder_list.first();
-                    }
-                    KeyEvent {
-                        code: KeyCode::End, ..
-                    } => {
-                        state.folder_list.last();
-                    }
-                    KeyEvent {
-                        code: KeyCode::Enter,
-                        ..
-                    } => {
-                        let selected = state.folder_list.state.selected();
-                        match selected {
-                            Some(index) => match state.folder_list.items.get(index) {
-                                Some(folder) => {
-                                    let name = folder.name.to_owned();
-                                    let id = folder.id;
-                                    state.active_folder = Some(name.clone());
-                                    debug!("Selected folder [{}] \"{}\"", id, name.clone());
-
-                                    match &state.api {
-                                        Some(api) => {
-                                            debug!(
-                                                "Reading the list of models for folder {}...",
-                                                id
-                                            );
-
-                                            let mut folders: Vec<u32> = Vec::new();
-                                            folders.push(id);
-
-                                            let models = api.list_all_models(folders, None, false);
-                                            match models {
-                                                Ok(models) => {
-                                                    debug!(
-                                                        "Found {} model(s)",
-                                                        models.models.len()
-                                                    );
-
-                                                    state.models_table.clear();
-                                                    models.models.iter().cloned().for_each(
-                                                        |model| {
-                                                            state.models_table.add_row(model);
-                                                        },
-                                                    );
-                                                }
-                                                Err(e) => error!("Error reading models: {}", e),
-                                            }
-                                        }
-                                        None => {
-                                            state.active_folder = None;
-                                            state.models_table.clear();
-                                        }
-                                    }
-                                }
-                                None => {
-                                    state.active_folder = None;
-                                    state.models_table.clear();
-                                }
-                            },
-                            None => {
-                                state.active_folder = None;
-                                state.models_table.clear();
-                                warn!("No folder selected");
-                            }
-                        }
-                    }
-                    _ => {}
-                },
-                _ => {}
-            },
-            InputMode::Model => match event {
-                Event::Key(key) => match key {
-                    KeyEvent {
-                        code: KeyCode::Esc, ..
-                    } => state.change_mode(InputMode::Normal),
-                    KeyEvent {
-                        code: KeyCode::Tab, ..
-                    } => state.change_mode(InputMode::Folder),
-                    KeyEvent {
-                        code: KeyCode::Up, ..
-                    } => {
-                        state.models_table.previous();
-                    }
-                    KeyEvent {
-                        code: KeyCode::Down,
-                        ..
-                    } => {
-                        state.models_table.next();
-                    }
-                    KeyEvent {
-                        code: KeyCode::Enter,
-                        ..
-                    } => {
-                        let selected = state.models_table.state.selected();
-                        match selected {
-                            Some(index) => {
-                                let selected_row = state.models_table.rows.get(index).ok_or(Err::<
-                                    String,
-                                    std::io::Error,
-                                >(
-                                    std::io::Error::new(
-                                        std::io::ErrorKind::Other,
-                                        "Incompatible model row item",
-                                    ),
-                                ));
-                                debug!("Selected model \"{}\"", selected_row.unwrap().uuid);
-                            }
-                            None => warn!("No model selected"),
-                        }
-                    }
-                    KeyEvent {
-                        code: KeyCode::Char('h'),
-                        ..
-                    } => {
-                        state.set_help(HelpType::Model);
-                        state.change_mode(InputMode::Help);
-                    }
-                    _ => {}
-                },
-                _ => {}
-            },
-            InputMode::Match => match event {
-                Event::Key(key) => match key {
-                    KeyEvent {
-                        code: KeyCode::Esc, ..
-                    } => state.change_mode(InputMode::Normal),
-                    KeyEvent {
-                        code: KeyCode::Char('h'),
-                        ..
-                    } => {
-                        state.set_help(HelpType::Match);
-                        state.change_mode(InputMode::Help);
-                    }
-                    _ => {}
-                },
-                _ => {}
-            },
-            InputMode::Help => match event {
-                Event::Key(key) => match key {
-                    _ => {
-                        let previous_mode = state.previous_mode;
-                        state.hide_help();
-                        state.change_mode(previous_mode);
-                    }
-                },
-                _ => {}
-            },
-            InputMode::Tenant => match event {
-                Event::Key(key) => match key {
-                    KeyEvent {
-                        code: KeyCode::Esc, ..
-                    } => {
-                        state.display_tenants = false;
-                        state.change_mode(InputMode::Normal)
-                    }
-                    KeyEvent {
-                        code: KeyCode::Char('h'),
-                        ..
-                    } => {
-                        state.set_help(HelpType::Tenant);
-                        state.change_mode(InputMode::Help);
-                    }
-                    KeyEvent {
-                        code: KeyCode::Up, ..
-                    } => {
-                        state.tenants.previous();
-                    }
-                    KeyEvent {
-                        code: KeyCode::Down,
-                        ..
-                    } => {
-                        state.tenants.next();
-                    }
-                    KeyEvent {
-                        code: KeyCode::Home,
-                        ..
-                    } => {
-                        state.tenants.first();
-                    }
-                    KeyEvent {
-                        code: KeyCode::End, ..
-                    } => {
-                        state.tenants.last();
-                    }
-                    KeyEvent {
-                        code: KeyCode::Enter,
-                        ..
-                    } => {
-                        let selected = state.tenants.state.selected();
-                        match selected {
-                            Some(index) => {
-                                let selected_item = state.tenants.items.get(index).ok_or(Err::<
-                                    String,
-                                    std::io::Error,
-                                >(
-                                    std::io::Error::new(
-                                        std::io::ErrorKind::Other,
-                                        "Incompatible tenant list item",
-                                    ),
-                                ));
-
-                                let active_tenant = selected_item.unwrap().to_owned();
-                                state.active_tenant = Some(active_tenant.clone());
-                                info!("Selected tenant \"{}\"", active_tenant.clone());
-
-                                match state.initialize_service() {
-                                    Ok(()) => {
-                                        debug!("Connected to the Physna service");
-                                    }
-                                    Err(e) => {
-                                        error!("Unable to connect to Physna, because of: {}", e)
-                                    }
-                                }
+        if let Event::Paste(content) = event {
+            if state.mode == InputMode::Search {
+                state.search_field.paste(&content);
+                state.refilter();
+            }
+            continue;
+        }
 
-                                // reloading the list of folders
-                                match &state.api {
-                                    Some(api) => {
-                                        let folders = api.get_list_of_folders();
-                                        match folders {
-                                            Ok(mut folders) => {
-                                                state.clear_folders();
-                                                folders.folders.sort();
-                                                folders.folders.iter().for_each(|f| {
-                                                    state.add_folder(f.clone());
-                                                });
-                                                debug!("List of folders ready");
-                                            }
-                                            Err(e) => {
-                                                error!("Failed to read the list of fodlers: {}", e);
-                                            }
-                                        }
-                                    }
-                                    None => {
-                                        warn!("No connection with Physna");
-                                    }
-                                }
+        let key = match event {
+            Event::Key(key) => key,
+            _ => continue,
+        };
 
-                                state.display_tenants = false;
-                                state.change_mode(InputMode::Normal);
-                            }
-                            None => {
-                                state.active_tenant = None;
-                                warn!("No tenant selected");
-                            }
-                        }
-                    }
-                    _ => {}
-                },
+        // Any key dismisses the help overlay, regardless of the keymap.
+        if let InputMode::Help = state.mode {
+            let previous_mode = state.previous_mode;
+            state.hide_help();
+            state.change_mode(previous_mode);
+            continue;
+        }
+
+        let action = state.keymap.resolve(state.mode, key);
+
+        // Unmapped keys in Search mode are forwarded to the text field
+        // instead of being treated as an unsupported binding. Bindings
+        // follow the usual readline/emacs conventions so the field's
+        // undo/redo, kill-ring and selection support are all reachable.
+        if let (InputMode::Search, Action::Noop) = (state.mode, action) {
+            let field = &mut state.search_field;
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+            match key.code {
+                KeyCode::Char('a') if ctrl => field.home(),
+                KeyCode::Char('e') if ctrl => field.end(),
+                KeyCode::Char('k') if ctrl => field.kill_to_end(),
+                KeyCode::Char('u') if ctrl => field.kill_to_home(),
+                KeyCode::Char('w') if ctrl => field.delete_word_backward(),
+                KeyCode::Char('y') if ctrl => field.yank(),
+                KeyCode::Char('z') if ctrl => field.undo(),
+                KeyCode::Char('r') if ctrl => field.redo(),
+                KeyCode::Char('c') if ctrl => {
+                    field.copy();
+                }
+                KeyCode::Char('x') if ctrl => {
+                    field.cut();
+                }
+                KeyCode::Char(c) => field.insert_character(c),
+                KeyCode::Backspace => field.backspace(),
+                KeyCode::Delete => field.delete(),
+                KeyCode::Left if ctrl && shift => field.select_word_left(),
+                KeyCode::Right if ctrl && shift => field.select_word_right(),
+                KeyCode::Left if ctrl => field.word_left(),
+                KeyCode::Right if ctrl => field.word_right(),
+                KeyCode::Left if shift => field.select_left(),
+                KeyCode::Right if shift => field.select_right(),
+                KeyCode::Left => field.left(),
+                KeyCode::Right => field.right(),
+                KeyCode::Home if shift => field.select_home(),
+                KeyCode::End if shift => field.select_end(),
+                KeyCode::Home => field.home(),
+                KeyCode::End => field.end(),
                 _ => {}
-            },
+            }
+            state.refilter();
+            continue;
+        }
+
+        if let (InputMode::Normal, Action::Noop) = (state.mode, action) {
+            warn!("Unsupported key binding. Press <h> for help");
+            state.status_line = String::from("Press <h> for help or <q> to exit");
+            continue;
+        }
+
+        state.apply(action)?;
+
+        if state.should_quit() {
+            return Ok(());
         }
     }
 }
@@ -919,24 +1391,9 @@ fn ui<B: Backend>(f: &mut Frame<B>, state: &mut RefMut<State>) {
     // Main container
     let app_container = Block::default()
         .title(Spans::from(vec![
-            Span::styled(
-                "Physna TUI (",
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                active_tenant,
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                ")",
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            ),
+            Span::styled("Physna TUI (", state.theme.title.into()),
+            Span::styled(active_tenant, state.theme.title_accent.into()),
+            Span::styled(")", state.theme.title.into()),
         ]))
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
@@ -961,7 +1418,14 @@ fn ui<B: Backend>(f: &mut Frame<B>, state: &mut RefMut<State>) {
 
     let content_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)].as_ref())
+        .constraints(
+            [
+                Constraint::Percentage(25),
+                Constraint::Percentage(50),
+                Constraint::Percentage(25),
+            ]
+            .as_ref(),
+        )
         .split(container_chunks[1]);
 
     folders_section(f, state, content_chunks[0]);
@@ -982,32 +1446,34 @@ fn ui<B: Backend>(f: &mut Frame<B>, state: &mut RefMut<State>) {
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .style(match state.mode {
-            InputMode::Model => Style::default().fg(Color::Yellow),
-            _ => Style::default(),
+            InputMode::Model => state.theme.active_border.into(),
+            _ => state.theme.inactive_border.into(),
         });
     f.render_widget(models_list_section_block, content_chunks[1]);
 
     models_section(f, state, content_chunks[1]);
 
+    preview_section(f, state, content_chunks[2]);
+
     let tui_w: TuiLoggerWidget = TuiLoggerWidget::default()
         .block(
             Block::default()
                 .title("Log")
-                .border_style(Style::default().fg(Color::White).bg(Color::Black))
+                .border_style(state.theme.log_border.into())
                 .borders(Borders::ALL),
         )
-        .style_error(Style::default().fg(Color::Red))
-        .style_debug(Style::default().fg(Color::Green))
-        .style_warn(Style::default().fg(Color::Yellow))
-        .style_trace(Style::default().fg(Color::Magenta))
-        .style_info(Style::default().fg(Color::Cyan))
+        .style_error(state.theme.log_error.into())
+        .style_debug(state.theme.log_debug.into())
+        .style_warn(state.theme.log_warn.into())
+        .style_trace(state.theme.log_trace.into())
+        .style_info(state.theme.log_info.into())
         .output_separator('|')
         .output_timestamp(Some("%F %H:%M:%S%.3f".to_string()))
         .output_level(Some(TuiLoggerLevelOutput::Long))
         .output_target(false)
         .output_file(false)
         .output_line(false)
-        .style(Style::default().fg(Color::White).bg(Color::Black));
+        .style(state.theme.log_border.into());
     f.render_widget(tui_w, container_chunks[2]);
 
     let status_block = Block::default().borders(Borders::NONE);
@@ -1019,7 +1485,7 @@ fn ui<B: Backend>(f: &mut Frame<B>, state: &mut RefMut<State>) {
     help_section(f, state);
 }
 
-fn folders_section<B: Backend>(f: &mut Frame<B>, state: &RefMut<State>, area: Rect) {
+fn folders_section<B: Backend>(f: &mut Frame<B>, state: &mut RefMut<State>, area: Rect) {
     let folder_list_chunk = Layout::default()
         .margin(2)
         .direction(Direction::Vertical)
@@ -1031,66 +1497,89 @@ fn folders_section<B: Backend>(f: &mut Frame<B>, state: &RefMut<State>, area: Re
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .style(match state.mode {
-            InputMode::Folder => Style::default().fg(Color::Yellow),
-            _ => Style::default(),
+            InputMode::Folder => state.theme.active_border.into(),
+            _ => state.theme.inactive_border.into(),
         });
     f.render_widget(folders_list_section_block, area);
 
     let visible_items: Vec<ListItem> = state
         .folder_list
-        .items
+        .visible_items()
         .iter()
-        .cloned()
-        .map(|i| ListItem::new(format!("[{: >4}] {}", i.id, i.name)))
+        .map(|node| {
+            ListItem::new(format!(
+                "{}{}[{: >4}] {}",
+                node.branch_prefix(),
+                node.expand_glyph(),
+                node.folder.id,
+                node.folder.name
+            ))
+        })
         .collect();
 
     let selection_indicator = format!(" {}", char::from_u32(0x25B6).unwrap());
     let folder_list = List::new(visible_items)
-        .highlight_style(
-            Style::default().add_modifier(Modifier::REVERSED),
-            // .fg(Color::Black)
-            // .bg(Color::LightBlue)
-            // .add_modifier(Modifier::BOLD),
-        )
+        .highlight_style(state.theme.selected_row.into())
         .highlight_symbol(selection_indicator.as_str());
 
     let mut folder_list_state = state.folder_list.state.clone();
     f.render_stateful_widget(folder_list, folder_list_chunk[0], &mut folder_list_state);
+
+    state.folder_viewport = folder_list_chunk[0].height as usize;
+    let scrollbar = Scrollbar::new(
+        state.folder_list.visible_items().len(),
+        state.folder_viewport,
+        state.folder_list.state.selected().unwrap_or(0),
+    )
+    .style(state.theme.inactive_border.into());
+    f.render_widget(scrollbar, folder_list_chunk[0]);
 }
 
 fn status_section<B: Backend>(f: &mut Frame<B>, state: &RefMut<State>, area: Rect) {
+    let sort_mode = match state.models_table.sorters.last() {
+        Some(sorter) => format!(" | Sort: {}", sorter.describe()),
+        None => String::new(),
+    };
+
+    let busy = match &state.busy {
+        Some(label) => format!(
+            " | {} {}",
+            SPINNER_FRAMES[state.spinner_tick % SPINNER_FRAMES.len()],
+            label
+        ),
+        None => String::new(),
+    };
+
     let text = vec![Spans::from(vec![
         Span::styled(
             format!(" {} ", char::from_u32(0x25B6).unwrap()),
-            Style::default().fg(Color::Blue),
-        ),
-        Span::styled(
-            format!("[{}]", state.mode),
-            Style::default().fg(Color::Black).bg(Color::Yellow),
+            state.theme.status_indicator.into(),
         ),
+        Span::styled(format!("[{}]", state.mode), state.theme.status_mode.into()),
         Span::styled(
             format!(" {}", state.status_line),
-            Style::default().fg(Color::Green),
+            state.theme.status_text.into(),
         ),
+        Span::styled(sort_mode, state.theme.status_text.into()),
+        Span::styled(busy, state.theme.status_text.into()),
     ])];
     let status_chunk = Layout::default()
         .horizontal_margin(1)
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(100)].as_ref())
         .split(area);
-    let status = Paragraph::new(text).style(Style::default().fg(Color::Green));
+    let status = Paragraph::new(text).style(state.theme.status_text.into());
     f.render_widget(status, status_chunk[0]);
 }
 
 fn search_section<B: Backend>(f: &mut Frame<B>, state: &mut RefMut<State>, area: Rect) {
-    state.search_field.set_style(Style::default());
     let search_block = Block::default()
         .title("Search")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .style(match state.mode {
-            InputMode::Search => Style::default().fg(Color::Yellow),
-            _ => Style::default(),
+            InputMode::Search => state.theme.active_border.into(),
+            _ => state.theme.inactive_border.into(),
         });
     f.render_widget(search_block.clone(), area);
 
@@ -1100,7 +1589,45 @@ fn search_section<B: Backend>(f: &mut Frame<B>, state: &mut RefMut<State>, area:
     };
 
     let edit_area = area.inner(&margin);
-    f.render_widget(state.search_field.widget(), edit_area);
+
+    // `TextField` has no `Widget` impl of its own (it stays UI-agnostic),
+    // so split its display text into graphemes here and highlight the
+    // active selection (or, absent one, just the cursor grapheme), the
+    // same way a selected list/table row is highlighted, but only
+    // while the field is actually focused.
+    let display_text = state.search_field.display_text();
+    let graphemes: Vec<&str> = display_text.graphemes(true).collect();
+    let cursor = state.search_field.index().min(graphemes.len());
+
+    let spans = if state.mode == InputMode::Search {
+        let highlight_style: Style = state.theme.selected_row.into();
+        match state.search_field.selection_range() {
+            Some((start, end)) => {
+                let start = start.min(graphemes.len());
+                let end = end.min(graphemes.len());
+                vec![
+                    Span::raw(graphemes[..start].concat()),
+                    Span::styled(graphemes[start..end].concat(), highlight_style),
+                    Span::raw(graphemes[end..].concat()),
+                ]
+            }
+            None => {
+                let mut spans = vec![Span::raw(graphemes[..cursor].concat())];
+                if cursor < graphemes.len() {
+                    spans.push(Span::styled(graphemes[cursor].to_string(), highlight_style));
+                    spans.push(Span::raw(graphemes[cursor + 1..].concat()));
+                } else {
+                    spans.push(Span::styled(" ", highlight_style));
+                }
+                spans
+            }
+        }
+    } else {
+        vec![Span::raw(display_text)]
+    };
+
+    let paragraph = Paragraph::new(vec![Spans::from(spans)]);
+    f.render_widget(paragraph, edit_area);
 }
 
 /// helper function to create a centered rect using up certain percentage of the available rect `r`
@@ -1130,19 +1657,49 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// Renders the current mode's key | action bindings as a table, so the
+/// help screen is always exactly what `state.keymap` resolves for that
+/// mode, never a hand-maintained string that can drift out of sync.
 fn help_section<B: Backend>(f: &mut Frame<B>, state: &RefMut<State>) {
     if state.show_help() {
-        let block = Block::default().title("Help").borders(Borders::ALL);
-        let area = centered_rect(50, 50, f.size());
+        let block = Block::default()
+            .title(format!("{} Mode Help", state.help_mode))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        let area = centered_rect(60, 60, f.size());
         f.render_widget(Clear, area); //this clears out the background
         f.render_widget(block, area);
 
-        let text = Paragraph::new(state.help_text.as_str()).wrap(Wrap { trim: true });
         let margin = Margin {
             horizontal: 2,
             vertical: 1,
         };
-        f.render_widget(text, area.inner(&margin));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)].as_ref())
+            .split(area.inner(&margin));
+
+        let header = Row::new(vec![Cell::from("Key"), Cell::from("Action")])
+            .style(state.theme.table_header.into())
+            .height(1)
+            .bottom_margin(1);
+
+        let rows = state.help_bindings.iter().map(|(keys, description)| {
+            Row::new(vec![
+                Cell::from(keys.clone()),
+                Cell::from(description.clone()),
+            ])
+            .height(1)
+        });
+
+        let table = Table::new(rows)
+            .header(header)
+            .widths(&[Constraint::Length(14), Constraint::Percentage(100)]);
+        f.render_widget(table, chunks[0]);
+
+        let hint =
+            Paragraph::new("Press any key to exit this help").style(state.theme.status_text.into());
+        f.render_widget(hint, chunks[1]);
     }
 }
 
@@ -1163,50 +1720,63 @@ fn tenant_selection_section<B: Backend>(f: &mut Frame<B>, state: &mut RefMut<Sta
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .style(match state.mode {
-                InputMode::Tenant => Style::default().fg(Color::Yellow),
-                _ => Style::default(),
+                InputMode::Tenant => state.theme.active_border.into(),
+                _ => state.theme.inactive_border.into(),
             });
         f.render_widget(tenant_list_section_block, area);
 
         // transform a vector of Strings to a vector of ListItems
         let visible_items: Vec<ListItem> = state
             .tenants
-            .items
+            .visible_items()
             .iter()
-            .cloned()
-            .map(|i| ListItem::new(i))
+            .map(|i| ListItem::new((*i).clone()))
             .collect();
 
         let selection_indicator = format!(" {}", char::from_u32(0x25B6).unwrap());
         let tenants_list = List::new(visible_items)
-            .highlight_style(
-                Style::default().add_modifier(Modifier::REVERSED),
-                // .fg(Color::Black)
-                // .bg(Color::LightBlue)
-                // .add_modifier(Modifier::BOLD),
-            )
+            .highlight_style(state.theme.selected_row.into())
             .highlight_symbol(selection_indicator.as_str());
 
-        f.render_stateful_widget(tenants_list, area.inner(&margin), &mut state.tenants.state);
+        let inner = area.inner(&margin);
+        f.render_stateful_widget(tenants_list, inner, &mut state.tenants.state);
+
+        state.tenant_viewport = inner.height as usize;
+        let scrollbar = Scrollbar::new(
+            state.tenants.visible_items().len(),
+            state.tenant_viewport,
+            state.tenants.state.selected().unwrap_or(0),
+        )
+        .style(state.theme.inactive_border.into());
+        f.render_widget(scrollbar, inner);
     }
 }
 
 fn models_section<B: Backend>(f: &mut Frame<B>, state: &mut RefMut<State>, area: Rect) {
-    let selected_style = Style::default().add_modifier(Modifier::REVERSED);
-    let normal_style = Style::default().bg(Color::White);
-    let header_cells = state.models_table.columns.iter().map(|h| {
-        Cell::from(*h).style(
-            Style::default()
-                .fg(Color::Black)
-                .add_modifier(Modifier::BOLD),
-        )
-    });
+    let selected_style: Style = state.theme.selected_row.into();
+    let normal_style: Style = state.theme.table_row.into();
+    let active_sorter = state.models_table.sorters.last().copied();
+    let header_style: Style = state.theme.table_header.into();
+    let header_cells = state
+        .models_table
+        .columns
+        .iter()
+        .zip(column::COLUMN_ORDER)
+        .map(|(h, column)| {
+            let label = match active_sorter {
+                Some(sorter) if sorter.column == column => {
+                    format!("{} {}", h, sorter.direction.arrow())
+                }
+                _ => h.to_string(),
+            };
+            Cell::from(label).style(header_style)
+        });
     let header = Row::new(header_cells)
         .style(normal_style)
         .height(1)
         .bottom_margin(1);
 
-    let rows = state.models_table.rows.iter().map(|model| {
+    let rows = state.models_table.visible_rows().into_iter().map(|model| {
         let mut cells: Vec<Cell> = Vec::new();
         cells.push(Cell::from(model.name.clone()));
         cells.push(Cell::from(model.state.clone()));
@@ -1229,5 +1799,196 @@ fn models_section<B: Backend>(f: &mut Frame<B>, state: &mut RefMut<State>, area:
         horizontal: 2,
         vertical: 1,
     };
-    f.render_stateful_widget(t, area.inner(&margin), &mut state.models_table.state);
+    let inner = area.inner(&margin);
+    f.render_stateful_widget(t, inner, &mut state.models_table.state);
+
+    let rows_height = inner.height.saturating_sub(2);
+    state.models_viewport = rows_height as usize;
+    let scrollbar_area = Rect {
+        x: inner.x,
+        y: inner.y + 2,
+        width: inner.width,
+        height: rows_height,
+    };
+    let scrollbar = Scrollbar::new(
+        state.models_table.visible_rows().len(),
+        state.models_viewport,
+        state.models_table.state.selected().unwrap_or(0),
+    )
+    .style(state.theme.inactive_border.into());
+    f.render_widget(scrollbar, scrollbar_area);
+}
+
+/// Renders a half-block thumbnail of the selected model, a placeholder
+/// while the fetch is in flight, or an empty pane when nothing is
+/// selected or no thumbnail could be loaded.
+fn preview_section<B: Backend>(f: &mut Frame<B>, state: &RefMut<State>, area: Rect) {
+    let preview_block = Block::default()
+        .title("Preview")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(state.theme.inactive_border.into());
+    f.render_widget(preview_block, area);
+
+    let margin = Margin {
+        horizontal: 1,
+        vertical: 1,
+    };
+    let inner = area.inner(&margin);
+
+    let placeholder = |message: &str| {
+        Paragraph::new(message.to_owned())
+            .alignment(Alignment::Center)
+            .style(state.theme.status_text.into())
+    };
+
+    match state.models_table.selected() {
+        Some(model) => match state.previews.state(&model.uuid.to_string()) {
+            Some(PreviewState::Ready(image)) => {
+                let lines = render_half_blocks(image, inner.width, inner.height);
+                f.render_widget(Paragraph::new(lines), inner);
+            }
+            Some(PreviewState::Loading) | None => {
+                f.render_widget(placeholder("Loading preview..."), inner);
+            }
+            Some(PreviewState::Failed(_)) => {
+                f.render_widget(placeholder("No preview available"), inner);
+            }
+        },
+        None => f.render_widget(placeholder("No model selected"), inner),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `State` can only be constructed through `pcli::configuration::initialize`,
+    // which parses a real `.pcli.conf` this sandbox has no sample of, so these
+    // tests exercise `StatefulList`/`StatefulTable` directly instead: the
+    // generic, `pcli`-independent containers `State::apply`'s navigation
+    // actions (`NextItem`/`PrevItem`/`MoveFirst`/`MoveLast`/`PageUp`/`PageDown`)
+    // delegate to. `Searchable` is already implemented for `String` in
+    // `fuzzy.rs`, so `StatefulList<String>` exercises `apply_filter` too.
+    // `StatefulTable`'s `apply_filter` also needs `Columnar`, which has no
+    // blanket impl, so this test-only impl gives every column the whole
+    // string.
+    impl Columnar for String {
+        fn column_value(&self, _column: SortColumn) -> String {
+            self.clone()
+        }
+    }
+
+    fn list(items: &[&str]) -> StatefulList<String> {
+        StatefulList::with_items(items.iter().map(|s| s.to_string()).collect())
+    }
+
+    #[test]
+    fn test_list_next_wraps_from_the_last_item_to_the_first() {
+        let mut list = list(&["a", "b", "c"]);
+        list.first();
+        list.next();
+        list.next();
+        assert_eq!(list.selected(), Some(&"c".to_string()));
+        list.next();
+        assert_eq!(list.selected(), Some(&"a".to_string()));
+    }
+
+    #[test]
+    fn test_list_previous_wraps_from_the_first_item_to_the_last() {
+        let mut list = list(&["a", "b", "c"]);
+        list.first();
+        list.previous();
+        assert_eq!(list.selected(), Some(&"c".to_string()));
+    }
+
+    #[test]
+    fn test_list_first_and_last() {
+        let mut list = list(&["a", "b", "c"]);
+        list.last();
+        assert_eq!(list.selected(), Some(&"c".to_string()));
+        list.first();
+        assert_eq!(list.selected(), Some(&"a".to_string()));
+    }
+
+    #[test]
+    fn test_list_page_up_and_down_clamp_to_the_ends() {
+        let mut list = list(&["a", "b", "c", "d", "e"]);
+        list.first();
+        list.page_down(2);
+        assert_eq!(list.selected(), Some(&"c".to_string()));
+        list.page_down(10);
+        assert_eq!(list.selected(), Some(&"e".to_string()));
+        list.page_up(10);
+        assert_eq!(list.selected(), Some(&"a".to_string()));
+    }
+
+    #[test]
+    fn test_list_navigation_on_an_empty_list_selects_nothing() {
+        let mut list: StatefulList<String> = StatefulList::default();
+        list.next();
+        assert_eq!(list.selected(), None);
+        list.first();
+        assert_eq!(list.selected(), None);
+    }
+
+    #[test]
+    fn test_list_apply_filter_narrows_visible_items_and_resets_out_of_range_selection() {
+        let mut list = list(&["widget", "gadget", "gizmo"]);
+        list.last();
+
+        list.apply_filter("widget");
+        assert_eq!(list.visible_items(), vec![&"widget".to_string()]);
+        assert_eq!(list.selected(), Some(&"widget".to_string()));
+
+        list.apply_filter("");
+        assert_eq!(list.visible_items().len(), 3);
+    }
+
+    #[test]
+    fn test_list_apply_filter_with_no_matches_selects_nothing() {
+        let mut list = list(&["widget", "gadget"]);
+        list.apply_filter("nope");
+        assert!(list.visible_items().is_empty());
+        assert_eq!(list.selected(), None);
+    }
+
+    fn table(rows: &[&str]) -> StatefulTable<'static, String> {
+        let mut table: StatefulTable<String> = StatefulTable::with_columns(vec!["name"]);
+        for row in rows {
+            table.add_row(row.to_string());
+        }
+        table
+    }
+
+    #[test]
+    fn test_table_next_wraps_from_the_last_row_to_the_first() {
+        let mut table = table(&["a", "b", "c"]);
+        table.state.select(Some(0));
+        table.next();
+        table.next();
+        assert_eq!(table.selected(), Some(&"c".to_string()));
+        table.next();
+        assert_eq!(table.selected(), Some(&"a".to_string()));
+    }
+
+    #[test]
+    fn test_table_page_up_and_down_clamp_to_the_ends() {
+        let mut table = table(&["a", "b", "c", "d", "e"]);
+        table.state.select(Some(0));
+        table.page_down(10);
+        assert_eq!(table.selected(), Some(&"e".to_string()));
+        table.page_up(10);
+        assert_eq!(table.selected(), Some(&"a".to_string()));
+    }
+
+    #[test]
+    fn test_table_apply_filter_narrows_visible_rows() {
+        let mut table = table(&["widget", "gadget", "gizmo"]);
+        table.apply_filter("widget");
+        assert_eq!(table.visible_rows(), vec![&"widget".to_string()]);
+
+        table.apply_filter("");
+        assert_eq!(table.visible_rows().len(), 3);
+    }
 }
@@ -0,0 +1,171 @@
+//! In-terminal thumbnail previews for the selected model.
+//!
+//! Follows yazi's approach to image preview: decode the fetched bytes
+//! with the `image` crate, downscale to the pane's cell dimensions, and
+//! render two source pixels per terminal cell as a `▀` glyph with the
+//! top pixel as its foreground color and the bottom pixel as its
+//! background color. This "half-block" technique works in any terminal
+//! that can show colored Unicode, so it's the fallback used here rather
+//! than a sixel/kitty escape sequence, which would only work in a
+//! subset of terminals.
+//!
+//! Decoded images are cached by model UUID, so scrolling back to a
+//! model already previewed this session doesn't refetch or redecode it.
+
+use image::{DynamicImage, GenericImageView};
+use std::collections::HashMap;
+use tui::{
+    style::{Color, Style},
+    text::{Span, Spans},
+};
+
+pub enum PreviewState {
+    Loading,
+    Ready(DynamicImage),
+    Failed(String),
+}
+
+#[derive(Default)]
+pub struct PreviewCache {
+    entries: HashMap<String, PreviewState>,
+}
+
+impl PreviewCache {
+    pub fn state(&self, uuid: &str) -> Option<&PreviewState> {
+        self.entries.get(uuid)
+    }
+
+    /// True the first time `uuid` is seen; once a fetch has been
+    /// started (loading, ready, or failed) it won't be requested again.
+    pub fn needs_fetch(&self, uuid: &str) -> bool {
+        !self.entries.contains_key(uuid)
+    }
+
+    pub fn mark_loading(&mut self, uuid: &str) {
+        self.entries.insert(uuid.to_string(), PreviewState::Loading);
+    }
+
+    pub fn store(&mut self, uuid: &str, result: Result<Vec<u8>, String>) {
+        let state = match result
+            .and_then(|bytes| image::load_from_memory(&bytes).map_err(|e| e.to_string()))
+        {
+            Ok(image) => PreviewState::Ready(image),
+            Err(e) => PreviewState::Failed(e),
+        };
+        self.entries.insert(uuid.to_string(), state);
+    }
+}
+
+/// Renders `image` as half-block Unicode art sized to fill a pane of
+/// `width` columns by `height` rows.
+pub fn render_half_blocks(image: &DynamicImage, width: u16, height: u16) -> Vec<Spans<'static>> {
+    if width == 0 || height == 0 {
+        return vec![];
+    }
+
+    let pixel_rows = height as u32 * 2;
+    let thumbnail = image.resize_exact(
+        width as u32,
+        pixel_rows,
+        image::imageops::FilterType::Triangle,
+    );
+
+    (0..height as u32)
+        .map(|row| {
+            let spans: Vec<Span<'static>> = (0..width as u32)
+                .map(|col| {
+                    let top = thumbnail.get_pixel(col, row * 2).0;
+                    let bottom = thumbnail.get_pixel(col, row * 2 + 1).0;
+                    Span::styled(
+                        "\u{2580}",
+                        Style::default()
+                            .fg(Color::Rgb(top[0], top[1], top[2]))
+                            .bg(Color::Rgb(bottom[0], bottom[1], bottom[2])),
+                    )
+                })
+                .collect();
+            Spans::from(spans)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageOutputFormat, Rgb, RgbImage};
+    use std::io::Cursor;
+
+    fn solid_image(width: u32, height: u32, color: [u8; 3]) -> DynamicImage {
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(width, height, Rgb(color)))
+    }
+
+    fn encode_png(image: &DynamicImage) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut bytes), ImageOutputFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_needs_fetch_is_true_until_marked_loading() {
+        let mut cache = PreviewCache::default();
+        assert!(cache.needs_fetch("uuid-1"));
+
+        cache.mark_loading("uuid-1");
+        assert!(!cache.needs_fetch("uuid-1"));
+        assert!(matches!(cache.state("uuid-1"), Some(PreviewState::Loading)));
+    }
+
+    #[test]
+    fn test_store_decodes_valid_bytes_into_ready() {
+        let mut cache = PreviewCache::default();
+        let bytes = encode_png(&solid_image(4, 4, [255, 0, 0]));
+
+        cache.store("uuid-1", Ok(bytes));
+
+        assert!(matches!(
+            cache.state("uuid-1"),
+            Some(PreviewState::Ready(_))
+        ));
+    }
+
+    #[test]
+    fn test_store_on_undecodable_bytes_is_failed() {
+        let mut cache = PreviewCache::default();
+        cache.store("uuid-1", Ok(vec![0, 1, 2, 3]));
+        assert!(matches!(
+            cache.state("uuid-1"),
+            Some(PreviewState::Failed(_))
+        ));
+    }
+
+    #[test]
+    fn test_store_on_a_fetch_error_is_failed_with_that_message() {
+        let mut cache = PreviewCache::default();
+        cache.store("uuid-1", Err("network error".to_string()));
+
+        match cache.state("uuid-1") {
+            Some(PreviewState::Failed(message)) => assert_eq!(message, "network error"),
+            other => panic!("expected Failed(..), got {:?}", other.is_some()),
+        }
+    }
+
+    #[test]
+    fn test_render_half_blocks_is_empty_for_a_zero_sized_pane() {
+        let image = solid_image(2, 2, [0, 0, 0]);
+        assert!(render_half_blocks(&image, 0, 4).is_empty());
+        assert!(render_half_blocks(&image, 4, 0).is_empty());
+    }
+
+    #[test]
+    fn test_render_half_blocks_colors_each_cell_from_its_two_source_pixels() {
+        let image = solid_image(2, 2, [10, 20, 30]);
+        let rendered = render_half_blocks(&image, 1, 1);
+
+        assert_eq!(rendered.len(), 1);
+        let span = &rendered[0].0[0];
+        assert_eq!(span.style.fg, Some(Color::Rgb(10, 20, 30)));
+        assert_eq!(span.style.bg, Some(Color::Rgb(10, 20, 30)));
+    }
+}
@@ -0,0 +1,295 @@
+//! Optional Lua scripting (via `mlua`), following trinitrix's and xplr's
+//! embedded-Lua approach: an `init.lua` sibling to the user's `.pcli.conf`
+//! is loaded once at startup, and can register named commands (bound to
+//! keys via `RunScript:<name>` in the keymap) plus lifecycle hooks that
+//! fire alongside `change_mode`, folder selection, and search execution.
+//! There being no `init.lua` is not an error; scripting is simply off.
+
+use log::{error, warn};
+use mlua::{Function, Lua, Table};
+use std::cell::RefCell;
+use std::path::Path;
+use std::rc::Rc;
+
+/// What a running command or hook sees and can change: the current
+/// tenant/folder, and a place to leave a requested change for `State` to
+/// pick up once the script returns.
+#[derive(Default)]
+struct Bridge {
+    active_tenant: Option<String>,
+    active_folder: Option<String>,
+    requested_tenant: Option<String>,
+    requested_folder: Option<String>,
+}
+
+pub struct ScriptEngine {
+    lua: Lua,
+    bridge: Rc<RefCell<Bridge>>,
+}
+
+impl ScriptEngine {
+    /// Loads and runs the `init.lua` sibling of `config_path` (the same
+    /// directory as `.pcli.conf`/`ptui.conf`), if it exists. Returns
+    /// `None` when there's no script, or it fails to install the API or
+    /// to run, so the caller can simply carry on without scripting.
+    pub fn load(config_path: &str) -> Option<ScriptEngine> {
+        let script_path = sibling_init_lua(config_path)?;
+        let source = std::fs::read_to_string(&script_path).ok()?;
+
+        let lua = Lua::new();
+        let bridge = Rc::new(RefCell::new(Bridge::default()));
+
+        if let Err(e) = install_api(&lua, bridge.clone()) {
+            error!("Failed to install the ptui Lua API: {}", e);
+            return None;
+        }
+
+        if let Err(e) = lua.load(&source).set_name("init.lua").exec() {
+            error!("Failed to run init.lua: {}", e);
+            return None;
+        }
+
+        Some(ScriptEngine { lua, bridge })
+    }
+
+    /// Refreshes the tenant/folder a script sees via `ptui.active_tenant()`
+    /// and `ptui.active_folder()` before it runs.
+    pub fn sync(&self, active_tenant: Option<&str>, active_folder: Option<&str>) {
+        let mut bridge = self.bridge.borrow_mut();
+        bridge.active_tenant = active_tenant.map(String::from);
+        bridge.active_folder = active_folder.map(String::from);
+    }
+
+    /// Drains any tenant/folder change a script requested via
+    /// `ptui.set_active_tenant`/`ptui.set_active_folder` during its last
+    /// run, so `State` can decide whether and how to apply it.
+    pub fn take_requests(&self) -> (Option<String>, Option<String>) {
+        let mut bridge = self.bridge.borrow_mut();
+        (
+            bridge.requested_tenant.take(),
+            bridge.requested_folder.take(),
+        )
+    }
+
+    /// Runs the command registered under `name` via `ptui.command`, if
+    /// any. Unknown command names are logged and otherwise ignored, the
+    /// same way an unbound key is.
+    pub fn run_command(&self, name: &str) {
+        let registered = self
+            .lua
+            .globals()
+            .get::<_, Table>("__commands")
+            .and_then(|commands| commands.get::<_, Option<Function>>(name))
+            .map(|function| function.is_some())
+            .unwrap_or(false);
+        if !registered {
+            warn!("No script command is registered as \"{}\"", name);
+            return;
+        }
+        self.call_hook("__commands", name, None);
+    }
+
+    pub fn on_mode_change(&self, mode: &str) {
+        self.call_hook("__hooks", "on_mode_change", Some(mode));
+    }
+
+    pub fn on_folder_selected(&self, folder: &str) {
+        self.call_hook("__hooks", "on_folder_selected", Some(folder));
+    }
+
+    pub fn on_search(&self, query: &str) {
+        self.call_hook("__hooks", "on_search", Some(query));
+    }
+
+    fn call_hook(&self, table_name: &str, key: &str, argument: Option<&str>) {
+        let table: Table = match self.lua.globals().get(table_name) {
+            Ok(table) => table,
+            Err(_) => return,
+        };
+
+        let function: Function = match table.get(key) {
+            Ok(function) => function,
+            Err(_) => return,
+        };
+
+        let result = match argument {
+            Some(argument) => function.call::<_, ()>(argument),
+            None => function.call::<_, ()>(()),
+        };
+
+        if let Err(e) = result {
+            error!("Error running Lua {} \"{}\": {}", table_name, key, e);
+        }
+    }
+}
+
+/// Installs the `ptui` global table: `command`/`on_mode_change`/
+/// `on_folder_selected`/`on_search` for registering callbacks, and
+/// `active_tenant`/`active_folder`/`set_active_tenant`/`set_active_folder`
+/// for reading and requesting changes to the current session.
+fn install_api(lua: &Lua, bridge: Rc<RefCell<Bridge>>) -> mlua::Result<()> {
+    let globals = lua.globals();
+
+    let commands = lua.create_table()?;
+    globals.set("__commands", commands.clone())?;
+
+    let hooks = lua.create_table()?;
+    globals.set("__hooks", hooks.clone())?;
+
+    let ptui = lua.create_table()?;
+
+    ptui.set(
+        "command",
+        lua.create_function(move |_, (name, function): (String, Function)| {
+            commands.set(name, function)
+        })?,
+    )?;
+
+    for hook_name in ["on_mode_change", "on_folder_selected", "on_search"] {
+        let hooks = hooks.clone();
+        ptui.set(
+            hook_name,
+            lua.create_function(move |_, function: Function| hooks.set(hook_name, function))?,
+        )?;
+    }
+
+    let read_bridge = bridge.clone();
+    ptui.set(
+        "active_tenant",
+        lua.create_function(move |_, ()| Ok(read_bridge.borrow().active_tenant.clone()))?,
+    )?;
+
+    let read_bridge = bridge.clone();
+    ptui.set(
+        "active_folder",
+        lua.create_function(move |_, ()| Ok(read_bridge.borrow().active_folder.clone()))?,
+    )?;
+
+    let write_bridge = bridge.clone();
+    ptui.set(
+        "set_active_tenant",
+        lua.create_function(move |_, name: String| {
+            write_bridge.borrow_mut().requested_tenant = Some(name);
+            Ok(())
+        })?,
+    )?;
+
+    ptui.set(
+        "set_active_folder",
+        lua.create_function(move |_, name: String| {
+            bridge.borrow_mut().requested_folder = Some(name);
+            Ok(())
+        })?,
+    )?;
+
+    globals.set("ptui", ptui)?;
+
+    Ok(())
+}
+
+fn sibling_init_lua(config_path: &str) -> Option<std::path::PathBuf> {
+    let parent = Path::new(config_path).parent()?;
+    Some(parent.join("init.lua"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh scratch directory with `config_path` pointing at a (never
+    /// created) config file inside it, so `sibling_init_lua` resolves
+    /// next to it the same way it would for a real `.pcli.conf`.
+    fn scratch_config_path(label: &str) -> std::path::PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("ptui_test_script_{}_{}", label, nanos));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join("ptui.conf")
+    }
+
+    fn engine_with_script(label: &str, source: &str) -> ScriptEngine {
+        let config_path = scratch_config_path(label);
+        let init_lua = sibling_init_lua(config_path.to_str().unwrap()).unwrap();
+        std::fs::write(&init_lua, source).unwrap();
+        ScriptEngine::load(config_path.to_str().unwrap()).expect("init.lua should load")
+    }
+
+    #[test]
+    fn test_load_returns_none_when_there_is_no_init_lua() {
+        let config_path = scratch_config_path("missing");
+        assert!(ScriptEngine::load(config_path.to_str().unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_load_returns_none_on_a_lua_syntax_error() {
+        let config_path = scratch_config_path("syntax_error");
+        let init_lua = sibling_init_lua(config_path.to_str().unwrap()).unwrap();
+        std::fs::write(&init_lua, "this is not valid lua (").unwrap();
+        assert!(ScriptEngine::load(config_path.to_str().unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_run_command_requests_a_tenant_and_folder_switch() {
+        let engine = engine_with_script(
+            "command",
+            r#"
+            ptui.command("switch", function()
+                ptui.set_active_tenant("acme")
+                ptui.set_active_folder("widgets")
+            end)
+            "#,
+        );
+
+        engine.run_command("switch");
+        assert_eq!(
+            engine.take_requests(),
+            (Some("acme".to_string()), Some("widgets".to_string()))
+        );
+        // Requests are drained, not re-readable a second time.
+        assert_eq!(engine.take_requests(), (None, None));
+    }
+
+    #[test]
+    fn test_run_command_on_an_unregistered_name_is_a_no_op() {
+        let engine = engine_with_script("noop", "");
+        engine.run_command("does-not-exist");
+        assert_eq!(engine.take_requests(), (None, None));
+    }
+
+    #[test]
+    fn test_sync_is_readable_from_active_tenant_and_active_folder() {
+        let engine = engine_with_script(
+            "sync",
+            r#"
+            ptui.command("record", function()
+                last_seen_tenant = ptui.active_tenant()
+                last_seen_folder = ptui.active_folder()
+            end)
+            "#,
+        );
+
+        engine.sync(Some("acme"), Some("widgets"));
+        engine.run_command("record");
+
+        let tenant: String = engine.lua.globals().get("last_seen_tenant").unwrap();
+        let folder: String = engine.lua.globals().get("last_seen_folder").unwrap();
+        assert_eq!(tenant, "acme");
+        assert_eq!(folder, "widgets");
+    }
+
+    #[test]
+    fn test_hooks_fire_with_their_argument() {
+        let engine = engine_with_script(
+            "hooks",
+            r#"
+            ptui.on_search(function(query) last_query = query end)
+            "#,
+        );
+
+        engine.on_search("widgets");
+        let query: String = engine.lua.globals().get("last_query").unwrap();
+        assert_eq!(query, "widgets");
+    }
+}
@@ -0,0 +1,102 @@
+//! A minimal right-edge scrollbar gauge for lists/tables, computed from
+//! the item count, viewport height, and selected position the way
+//! gobang renders its list scrollbars. Hand-rolled because this `tui`
+//! version has no built-in `Scrollbar` widget.
+
+use tui::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
+
+pub struct Scrollbar {
+    total: usize,
+    viewport: usize,
+    position: usize,
+    style: Style,
+}
+
+impl Scrollbar {
+    pub fn new(total: usize, viewport: usize, position: usize) -> Scrollbar {
+        Scrollbar {
+            total,
+            viewport,
+            position,
+            style: Style::default(),
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Scrollbar {
+        self.style = style;
+        self
+    }
+}
+
+impl Widget for Scrollbar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 || self.total <= self.viewport {
+            return;
+        }
+
+        let track_height = area.height as usize;
+        let thumb_height = ((self.viewport * track_height) / self.total)
+            .max(1)
+            .min(track_height);
+        let max_offset = self.total.saturating_sub(self.viewport).max(1);
+        let max_thumb_top = track_height.saturating_sub(thumb_height);
+        // `position` is the raw selected index, which can run past
+        // `max_offset` once the selection is deeper than the first
+        // screenful; clamp it so the thumb never gets computed past the
+        // bottom of the track.
+        let thumb_top = (self.position.min(max_offset) * max_thumb_top) / max_offset;
+
+        let x = area.right() - 1;
+        for y in 0..track_height {
+            let glyph = if y >= thumb_top && y < thumb_top + thumb_height {
+                '\u{2588}'
+            } else {
+                '\u{2502}'
+            };
+            buf.get_mut(x, area.top() + y as u16)
+                .set_char(glyph)
+                .set_style(self.style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui::buffer::Buffer;
+
+    /// Renders `scrollbar` into a track of `height` rows and returns the
+    /// glyph in each row, top to bottom.
+    fn render_track(scrollbar: Scrollbar, height: u16) -> Vec<char> {
+        let area = Rect::new(0, 0, 1, height);
+        let mut buf = Buffer::empty(area);
+        scrollbar.render(area, &mut buf);
+        (0..height)
+            .map(|y| buf.get(0, y).symbol.chars().next().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_thumb_sits_at_top_when_position_is_zero() {
+        let track = render_track(Scrollbar::new(100, 10, 0), 10);
+        assert_eq!(track[0], '\u{2588}');
+        assert_eq!(track[9], '\u{2502}');
+    }
+
+    #[test]
+    fn test_thumb_clamps_to_the_bottom_of_the_track_past_max_offset() {
+        // A selection far past the first screenful used to compute a
+        // `thumb_top` beyond the track and never render (9da15ae);
+        // it should instead clamp to the last possible position.
+        let unclamped = render_track(Scrollbar::new(100, 10, 1_000), 10);
+        let clamped = render_track(Scrollbar::new(100, 10, 90), 10);
+        assert_eq!(unclamped, clamped);
+        assert!(unclamped.contains(&'\u{2588}'));
+    }
+
+    #[test]
+    fn test_nothing_renders_when_everything_fits_in_the_viewport() {
+        let track = render_track(Scrollbar::new(10, 10, 0), 10);
+        assert!(track.iter().all(|&c| c == ' '));
+    }
+}
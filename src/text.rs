@@ -1,4 +1,10 @@
+use std::collections::VecDeque;
 use std::error::Error;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// How many most-recent kills the kill ring keeps; `yank()` re-inserts
+/// only the newest of them.
+const KILL_RING_CAPACITY: usize = 8;
 
 #[derive(Debug, Clone)]
 pub enum TextFieldError {
@@ -18,7 +24,42 @@ impl std::fmt::Display for TextFieldError {
 #[derive(Debug, Clone)]
 pub struct TextField {
     text: String,
+    /// Byte offset each extended grapheme cluster starts at, plus a
+    /// trailing entry equal to `text.len()`. Rebuilt whenever `text`
+    /// changes so `index` can count grapheme clusters (what a user
+    /// thinks of as one "character", including combining marks and
+    /// flag/regional-indicator sequences) instead of raw bytes.
+    boundaries: Vec<usize>,
     index: usize,
+    /// Snapshots of `(text, index)` taken before each edit, most recent
+    /// last. `undo()` pops one off here and pushes the current state
+    /// onto `redo_stack`; `redo()` does the reverse.
+    undo_stack: Vec<(String, usize)>,
+    redo_stack: Vec<(String, usize)>,
+    /// `true` while the in-progress edit is still coalescible with the
+    /// next one (set by single-character insertions, cleared by any
+    /// other mutation or cursor movement), so a typed word undoes in
+    /// one step instead of one `undo()` per keystroke.
+    coalescing: bool,
+    /// Most-recently-killed text first; fed by the word-wise and
+    /// to-end/to-home deletes, drained (non-destructively) by `yank()`.
+    kill_ring: VecDeque<String>,
+    /// The other end of the active selection, in grapheme units;
+    /// `None` means no selection. The selected range always runs from
+    /// `min(anchor, index)` to `max(anchor, index)`.
+    anchor: Option<usize>,
+    /// Glyph `display_text()` substitutes for every grapheme; `None`
+    /// means render the real text (the normal, unmasked mode).
+    mask: Option<char>,
+    /// When masked, whether `display_text()` should briefly show the
+    /// most recently typed grapheme in the clear.
+    reveal_last_char: bool,
+    /// Grapheme index of the character currently being revealed, if
+    /// any; cleared by every mutation/movement except the single
+    /// character insertions it's set by. The caller drives how long it
+    /// stays set (e.g. by calling `clear_reveal()` after its own
+    /// timer), which is why this isn't itself a timestamp.
+    last_inserted_index: Option<usize>,
 }
 
 impl Default for TextField {
@@ -31,17 +72,164 @@ impl TextField {
     pub fn new() -> TextField {
         TextField {
             text: String::default(),
+            boundaries: vec![0],
             index: 0,
+            undo_stack: vec![],
+            redo_stack: vec![],
+            coalescing: false,
+            kill_ring: VecDeque::new(),
+            anchor: None,
+            mask: None,
+            reveal_last_char: false,
+            last_inserted_index: None,
         }
     }
 
+    fn grapheme_at(&self, index: usize) -> &str {
+        let start = self.byte_offset(index);
+        let end = self.byte_offset(index + 1);
+        &self.text[start..end]
+    }
+
+    pub fn set_masked(&mut self, mask: char) {
+        self.mask = Some(mask);
+    }
+
+    pub fn set_plain(&mut self) {
+        self.mask = None;
+    }
+
+    pub fn set_reveal_last_char(&mut self, reveal: bool) {
+        self.reveal_last_char = reveal;
+    }
+
+    /// Re-masks the currently revealed grapheme, if any; callers using
+    /// `reveal_last_char` call this once their own reveal timer expires.
+    pub fn clear_reveal(&mut self) {
+        self.last_inserted_index = None;
+    }
+
+    /// What the UI should render: the real text in plain mode, or
+    /// `mask` repeated once per grapheme when masked — except the most
+    /// recently typed grapheme, shown in the clear while
+    /// `reveal_last_char` is on and hasn't been cleared yet. Navigation
+    /// and editing always operate on `text`/`index` directly, never on
+    /// this.
+    pub fn display_text(&self) -> String {
+        let mask = match self.mask {
+            None => return self.text.clone(),
+            Some(mask) => mask,
+        };
+
+        (0..self.len())
+            .map(|i| {
+                if self.reveal_last_char && self.last_inserted_index == Some(i) {
+                    self.grapheme_at(i).to_string()
+                } else {
+                    mask.to_string()
+                }
+            })
+            .collect()
+    }
+
+    fn rebuild_boundaries(&mut self) {
+        self.boundaries = self
+            .text
+            .grapheme_indices(true)
+            .map(|(offset, _)| offset)
+            .collect();
+        self.boundaries.push(self.text.len());
+    }
+
+    /// Number of grapheme clusters in the text; `index` ranges from `0`
+    /// to this, inclusive.
+    fn len(&self) -> usize {
+        self.boundaries.len() - 1
+    }
+
+    /// Byte offset of grapheme `index`, or one-past-the-end when
+    /// `index == len()`.
+    fn byte_offset(&self, index: usize) -> usize {
+        self.boundaries[index]
+    }
+
     pub fn text(&self) -> String {
         self.text.clone()
     }
 
     pub fn set_text(&mut self, text: &String) {
         self.text = text.clone();
-        self.index = self.text.len();
+        self.rebuild_boundaries();
+        self.index = self.len();
+        self.coalescing = false;
+        self.anchor = None;
+        self.last_inserted_index = None;
+    }
+
+    /// Records the pre-edit `(text, index)` for `undo()`, unless this
+    /// edit coalesces with the one just before it. Clears the redo
+    /// stack, since a fresh edit invalidates it.
+    fn record_edit(&mut self, coalesce: bool) {
+        if !(coalesce && self.coalescing) {
+            self.undo_stack.push((self.text.clone(), self.index));
+            self.redo_stack.clear();
+        }
+        self.coalescing = coalesce;
+    }
+
+    /// Cursor movement doesn't edit the text, but it does end the
+    /// current coalescing group, so typing, moving, then typing again
+    /// produces two separate undo steps.
+    fn break_coalescing(&mut self) {
+        self.coalescing = false;
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    pub fn undo(&mut self) {
+        if let Some((text, index)) = self.undo_stack.pop() {
+            self.redo_stack.push((self.text.clone(), self.index));
+            self.text = text;
+            self.rebuild_boundaries();
+            self.index = index.min(self.len());
+            self.coalescing = false;
+            self.anchor = None;
+            self.last_inserted_index = None;
+        }
+    }
+
+    pub fn redo(&mut self) {
+        if let Some((text, index)) = self.redo_stack.pop() {
+            self.undo_stack.push((self.text.clone(), self.index));
+            self.text = text;
+            self.rebuild_boundaries();
+            self.index = index.min(self.len());
+            self.coalescing = false;
+            self.anchor = None;
+            self.last_inserted_index = None;
+        }
+    }
+
+    fn push_kill(&mut self, killed: String) {
+        if killed.is_empty() {
+            return;
+        }
+        self.kill_ring.push_front(killed);
+        self.kill_ring.truncate(KILL_RING_CAPACITY);
+    }
+
+    /// Re-inserts the most recent kill at the cursor, if the ring isn't
+    /// empty.
+    pub fn yank(&mut self) {
+        if let Some(killed) = self.kill_ring.front().cloned() {
+            self.insert_string(&killed);
+        }
     }
 
     pub fn is_empty(&self) -> bool {
@@ -53,7 +241,10 @@ impl TextField {
     }
 
     pub fn set_index(&mut self, index: usize) -> Result<(), TextFieldError> {
-        if (index >= 0) && (index <= self.text.len()) {
+        if index <= self.len() {
+            self.break_coalescing();
+            self.anchor = None;
+            self.last_inserted_index = None;
             self.index = index;
         } else {
             return Err(TextFieldError::InvalidIndexPosition);
@@ -62,66 +253,389 @@ impl TextField {
         Ok(())
     }
 
+    /// Anchors a selection at the current cursor position; subsequent
+    /// `select_*` calls extend it from here.
+    pub fn set_anchor(&mut self) {
+        self.anchor = Some(self.index);
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// The active selection as `(start, end)` grapheme indices, always
+    /// ordered regardless of which end the cursor is on.
+    pub fn selection_range(&self) -> Option<(usize, usize)> {
+        self.anchor
+            .map(|anchor| (anchor.min(self.index), anchor.max(self.index)))
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        self.selection_range().map(|(start, end)| {
+            let start_byte = self.byte_offset(start);
+            let end_byte = self.byte_offset(end);
+            self.text[start_byte..end_byte].to_string()
+        })
+    }
+
+    /// Starts (or continues) a selection before a shift-style cursor
+    /// move, and ends the current undo-coalescing group the same as
+    /// any other cursor movement.
+    fn begin_or_continue_selection(&mut self) {
+        self.break_coalescing();
+        self.last_inserted_index = None;
+        if self.anchor.is_none() {
+            self.anchor = Some(self.index);
+        }
+    }
+
+    pub fn select_left(&mut self) {
+        self.begin_or_continue_selection();
+        if self.index > 0 {
+            self.index -= 1;
+        }
+    }
+
+    pub fn select_right(&mut self) {
+        self.begin_or_continue_selection();
+        if self.index < self.len() {
+            self.index += 1;
+        }
+    }
+
+    pub fn select_word_left(&mut self) {
+        self.begin_or_continue_selection();
+        self.index = self.word_left_index(self.index);
+    }
+
+    pub fn select_word_right(&mut self) {
+        self.begin_or_continue_selection();
+        self.index = self.word_right_index(self.index);
+    }
+
+    pub fn select_home(&mut self) {
+        self.begin_or_continue_selection();
+        self.index = 0;
+    }
+
+    pub fn select_end(&mut self) {
+        self.begin_or_continue_selection();
+        self.index = self.len();
+    }
+
+    /// Deletes the active selection, if any, and collapses the cursor
+    /// to where it started.
+    pub fn delete_selection(&mut self) {
+        if self.selection_range().is_some() {
+            self.record_edit(false);
+            self.replace_selection();
+            self.last_inserted_index = None;
+        }
+    }
+
+    /// The shared first step behind typing or pasting over a
+    /// selection: remove it and collapse the cursor, leaving nothing
+    /// selected. A no-op when there is no selection. Does not itself
+    /// record an undo step — callers that aren't already inside one
+    /// (`delete_selection`) must call `record_edit` first.
+    fn replace_selection(&mut self) {
+        if let Some((start, end)) = self.selection_range() {
+            if end > start {
+                let start_byte = self.byte_offset(start);
+                let end_byte = self.byte_offset(end);
+                self.text.replace_range(start_byte..end_byte, "");
+                self.rebuild_boundaries();
+                self.index = start;
+            }
+            self.anchor = None;
+        }
+    }
+
+    /// Copies the active selection onto the kill ring so `yank()` can
+    /// paste it elsewhere; the selection itself is left untouched.
+    pub fn copy(&mut self) -> Option<String> {
+        let selected = self.selected_text();
+        if let Some(text) = &selected {
+            self.push_kill(text.clone());
+        }
+        selected
+    }
+
+    /// Copies the active selection onto the kill ring, then deletes it.
+    pub fn cut(&mut self) -> Option<String> {
+        let selected = self.copy();
+        if selected.is_some() {
+            self.delete_selection();
+        }
+        selected
+    }
+
+    /// Inserts `content` at the cursor, replacing the active selection
+    /// first if there is one, and leaves the cursor just past the
+    /// pasted text.
+    pub fn paste(&mut self, content: &str) {
+        self.record_edit(false);
+        self.replace_selection();
+        self.last_inserted_index = None;
+        let offset = self.byte_offset(self.index);
+        self.text.insert_str(offset, content);
+        self.rebuild_boundaries();
+        self.index += content.graphemes(true).count();
+    }
+
     pub fn clear(&mut self) {
+        self.record_edit(false);
         self.text = String::default();
+        self.rebuild_boundaries();
         self.index = 0;
+        self.anchor = None;
+        self.last_inserted_index = None;
     }
 
     pub fn append_character(&mut self, character: char) {
-        let mut text = self.text();
-        text.push(character);
-        self.set_text(&text);
-        self.index = self.text.len();
+        self.record_edit(true);
+        self.anchor = None;
+        self.text.push(character);
+        self.rebuild_boundaries();
+        self.index = self.len();
+        self.last_inserted_index = Some(self.index - 1);
     }
 
     pub fn append_string(&mut self, another_string: &String) {
-        let mut text = self.text();
-        text.push_str(another_string.as_str());
-        self.set_text(&text);
-        self.index = self.text.len();
+        self.record_edit(false);
+        self.anchor = None;
+        self.last_inserted_index = None;
+        self.text.push_str(another_string.as_str());
+        self.rebuild_boundaries();
+        self.index = self.len();
     }
 
     pub fn insert_character(&mut self, character: char) {
-        self.text.insert(self.index, character);
+        self.record_edit(true);
+        self.replace_selection();
+        let offset = self.byte_offset(self.index);
+        self.text.insert(offset, character);
+        self.rebuild_boundaries();
         self.index += 1;
+        self.last_inserted_index = Some(self.index - 1);
     }
 
     pub fn insert_string(&mut self, another_string: &String) {
-        self.text.insert_str(self.index, another_string.as_str());
+        self.record_edit(false);
+        self.replace_selection();
+        self.last_inserted_index = None;
+        let offset = self.byte_offset(self.index);
+        self.text.insert_str(offset, another_string.as_str());
+        self.rebuild_boundaries();
     }
 
     pub fn left(&mut self) {
+        self.break_coalescing();
+        self.anchor = None;
+        self.last_inserted_index = None;
         if self.index > 0 {
             self.index -= 1;
         }
     }
 
     pub fn right(&mut self) {
-        if self.index < self.text.len() {
+        self.break_coalescing();
+        self.anchor = None;
+        self.last_inserted_index = None;
+        if self.index < self.len() {
             self.index += 1;
         }
     }
 
     pub fn delete(&mut self) {
-        if self.text.len() > 0 {
-            self.text.remove(self.index);
+        self.last_inserted_index = None;
+        if self.selection_range().is_some() {
+            self.delete_selection();
+            return;
+        }
+        if self.index < self.len() {
+            self.record_edit(false);
+            let start = self.byte_offset(self.index);
+            let end = self.byte_offset(self.index + 1);
+            self.text.replace_range(start..end, "");
+            self.rebuild_boundaries();
         }
     }
 
     pub fn backspace(&mut self) {
+        self.last_inserted_index = None;
+        if self.selection_range().is_some() {
+            self.delete_selection();
+            return;
+        }
         if self.index > 0 {
-            self.left();
-            self.delete();
+            self.record_edit(false);
+            let start = self.byte_offset(self.index - 1);
+            let end = self.byte_offset(self.index);
+            self.text.replace_range(start..end, "");
+            self.rebuild_boundaries();
+            self.index -= 1;
         }
     }
 
     pub fn end(&mut self) {
-        self.index = self.text.len();
+        self.break_coalescing();
+        self.anchor = None;
+        self.last_inserted_index = None;
+        self.index = self.len();
     }
 
     pub fn home(&mut self) {
+        self.break_coalescing();
+        self.anchor = None;
+        self.last_inserted_index = None;
         self.index = 0;
     }
+
+    /// Kills from the cursor to the end of the text, storing the
+    /// removed text on the kill ring.
+    pub fn kill_to_end(&mut self) {
+        self.last_inserted_index = None;
+        if self.selection_range().is_some() {
+            self.cut();
+            return;
+        }
+        if self.index < self.len() {
+            let start_byte = self.byte_offset(self.index);
+            let killed = self.text[start_byte..].to_string();
+            self.record_edit(false);
+            self.text.truncate(start_byte);
+            self.rebuild_boundaries();
+            self.push_kill(killed);
+        }
+    }
+
+    /// Kills from the start of the text to the cursor, storing the
+    /// removed text on the kill ring.
+    pub fn kill_to_home(&mut self) {
+        self.last_inserted_index = None;
+        if self.selection_range().is_some() {
+            self.cut();
+            return;
+        }
+        if self.index > 0 {
+            let end_byte = self.byte_offset(self.index);
+            let killed = self.text[..end_byte].to_string();
+            self.record_edit(false);
+            self.text.replace_range(..end_byte, "");
+            self.rebuild_boundaries();
+            self.index = 0;
+            self.push_kill(killed);
+        }
+    }
+
+    fn class_at(&self, index: usize) -> CharClass {
+        CharClass::of(self.grapheme_at(index))
+    }
+
+    /// Index one word to the right of `from`: skip a run of whitespace,
+    /// then a run of same-class characters (word or punctuation).
+    fn word_right_index(&self, from: usize) -> usize {
+        let len = self.len();
+        let mut i = from;
+        while i < len && self.class_at(i) == CharClass::Whitespace {
+            i += 1;
+        }
+        if i < len {
+            let class = self.class_at(i);
+            while i < len && self.class_at(i) == class {
+                i += 1;
+            }
+        }
+        i
+    }
+
+    /// Mirror of `word_right_index`, skipping backward instead.
+    fn word_left_index(&self, from: usize) -> usize {
+        let mut i = from;
+        while i > 0 && self.class_at(i - 1) == CharClass::Whitespace {
+            i -= 1;
+        }
+        if i > 0 {
+            let class = self.class_at(i - 1);
+            while i > 0 && self.class_at(i - 1) == class {
+                i -= 1;
+            }
+        }
+        i
+    }
+
+    pub fn word_right(&mut self) {
+        self.break_coalescing();
+        self.anchor = None;
+        self.last_inserted_index = None;
+        self.index = self.word_right_index(self.index);
+    }
+
+    pub fn word_left(&mut self) {
+        self.break_coalescing();
+        self.anchor = None;
+        self.last_inserted_index = None;
+        self.index = self.word_left_index(self.index);
+    }
+
+    pub fn delete_word_forward(&mut self) {
+        self.last_inserted_index = None;
+        if self.selection_range().is_some() {
+            self.cut();
+            return;
+        }
+        let end = self.word_right_index(self.index);
+        if end > self.index {
+            let start_byte = self.byte_offset(self.index);
+            let end_byte = self.byte_offset(end);
+            let killed = self.text[start_byte..end_byte].to_string();
+            self.record_edit(false);
+            self.text.replace_range(start_byte..end_byte, "");
+            self.rebuild_boundaries();
+            self.push_kill(killed);
+        }
+    }
+
+    pub fn delete_word_backward(&mut self) {
+        self.last_inserted_index = None;
+        if self.selection_range().is_some() {
+            self.cut();
+            return;
+        }
+        let start = self.word_left_index(self.index);
+        if start < self.index {
+            let start_byte = self.byte_offset(start);
+            let end_byte = self.byte_offset(self.index);
+            let killed = self.text[start_byte..end_byte].to_string();
+            self.record_edit(false);
+            self.text.replace_range(start_byte..end_byte, "");
+            self.rebuild_boundaries();
+            self.index = start;
+            self.push_kill(killed);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Whitespace,
+    Word,
+    Other,
+}
+
+impl CharClass {
+    /// Classifies a grapheme cluster by its first `char`: alphanumerics
+    /// and underscore are "word" characters, everything else splits
+    /// into whitespace vs. punctuation/symbol runs.
+    fn of(grapheme: &str) -> CharClass {
+        match grapheme.chars().next() {
+            Some(c) if c.is_whitespace() => CharClass::Whitespace,
+            Some(c) if c.is_alphanumeric() || c == '_' => CharClass::Word,
+            Some(_) => CharClass::Other,
+            None => CharClass::Whitespace,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -203,4 +717,272 @@ mod tests {
         text.insert_string(&String::from("You are "));
         assert_eq!(text.text(), "You are extra special");
     }
+
+    #[test]
+    fn test_text_field_multi_byte_graphemes() {
+        let mut text = TextField::new();
+
+        // Combining marks: "é" here is "e" + U+0301 COMBINING ACUTE
+        // ACCENT, one grapheme cluster made of two chars.
+        text.set_text(&String::from("cafe\u{0301}"));
+        assert_eq!(text.index(), 4);
+
+        text.left();
+        assert_eq!(text.index(), 3);
+        text.right();
+        assert_eq!(text.index(), 4);
+
+        text.backspace();
+        assert_eq!(text.text(), "caf");
+        assert_eq!(text.index(), 3);
+
+        // CJK and a flag (regional-indicator pair) each count as one
+        // grapheme cluster too.
+        text.clear();
+        text.insert_character('日');
+        text.insert_character('本');
+        assert_eq!(text.index(), 2);
+        text.home();
+        text.delete();
+        assert_eq!(text.text(), "本");
+        assert_eq!(text.index(), 0);
+
+        text.clear();
+        text.append_string(&String::from("\u{1F1EF}\u{1F1F5}"));
+        assert_eq!(text.index(), 1);
+        text.backspace();
+        assert!(text.is_empty());
+    }
+
+    #[test]
+    fn test_text_field_word_navigation() {
+        let mut text = TextField::new();
+        text.set_text(&String::from("foo  bar-baz"));
+        text.home();
+
+        text.word_right();
+        assert_eq!(text.index(), 3);
+
+        text.word_right();
+        assert_eq!(text.index(), 8);
+
+        text.word_right();
+        assert_eq!(text.index(), 9);
+
+        text.word_right();
+        assert_eq!(text.index(), 12);
+
+        text.word_left();
+        assert_eq!(text.index(), 9);
+
+        text.word_left();
+        assert_eq!(text.index(), 8);
+
+        text.word_left();
+        assert_eq!(text.index(), 5);
+
+        text.word_left();
+        assert_eq!(text.index(), 0);
+    }
+
+    #[test]
+    fn test_text_field_word_deletion() {
+        let mut text = TextField::new();
+
+        text.set_text(&String::from("foo bar"));
+        text.home();
+        text.delete_word_forward();
+        assert_eq!(text.text(), " bar");
+        assert_eq!(text.index(), 0);
+
+        text.set_text(&String::from("foo bar"));
+        text.end();
+        text.delete_word_backward();
+        assert_eq!(text.text(), "foo ");
+        assert_eq!(text.index(), 4);
+    }
+
+    #[test]
+    fn test_text_field_undo_redo() {
+        let mut text = TextField::new();
+        assert!(!text.can_undo());
+        assert!(!text.can_redo());
+
+        // Consecutive single-character insertions coalesce into one group.
+        text.insert_character('f');
+        text.insert_character('o');
+        text.insert_character('o');
+        assert_eq!(text.text(), "foo");
+        assert!(text.can_undo());
+
+        text.undo();
+        assert_eq!(text.text(), "");
+        assert!(!text.can_undo());
+        assert!(text.can_redo());
+
+        text.redo();
+        assert_eq!(text.text(), "foo");
+        assert!(!text.can_redo());
+
+        // Moving the cursor breaks the coalescing group, so typing
+        // again starts a new undo step.
+        text.home();
+        text.insert_character('!');
+        assert_eq!(text.text(), "!foo");
+
+        text.undo();
+        assert_eq!(text.text(), "foo");
+
+        text.undo();
+        assert_eq!(text.text(), "");
+        assert!(!text.can_undo());
+
+        // A non-coalescible edit (delete) is its own undo step.
+        text.set_text(&String::from("hello"));
+        text.home();
+        text.delete();
+        assert_eq!(text.text(), "ello");
+        text.undo();
+        assert_eq!(text.text(), "hello");
+    }
+
+    #[test]
+    fn test_text_field_kill_ring() {
+        let mut text = TextField::new();
+
+        text.set_text(&String::from("foo bar"));
+        text.home();
+        text.delete_word_forward();
+        assert_eq!(text.text(), " bar");
+
+        text.end();
+        text.yank();
+        assert_eq!(text.text(), " barfoo");
+
+        text.set_text(&String::from("one two"));
+        text.end();
+        text.kill_to_home();
+        assert_eq!(text.text(), "");
+        assert_eq!(text.index(), 0);
+
+        text.yank();
+        assert_eq!(text.text(), "one two");
+    }
+
+    #[test]
+    fn test_text_field_selection_range() {
+        let mut text = TextField::new();
+        text.set_text(&String::from("hello world"));
+        text.home();
+
+        text.select_word_right();
+        assert_eq!(text.selection_range(), Some((0, 5)));
+        assert_eq!(text.selected_text(), Some(String::from("hello")));
+
+        // From a space, word-right selection jumps clear to the end of
+        // the following word.
+        text.select_word_right();
+        assert_eq!(text.selection_range(), Some((0, 11)));
+        assert_eq!(text.selected_text(), Some(String::from("hello world")));
+
+        text.clear_selection();
+        assert_eq!(text.selection_range(), None);
+
+        // Plain (non-shift) movement also collapses any selection.
+        text.home();
+        text.select_right();
+        assert!(text.selection_range().is_some());
+        text.right();
+        assert_eq!(text.selection_range(), None);
+    }
+
+    #[test]
+    fn test_text_field_clipboard() {
+        let mut text = TextField::new();
+        text.set_text(&String::from("hello world"));
+        text.home();
+        text.select_word_right();
+
+        let copied = text.copy();
+        assert_eq!(copied, Some(String::from("hello")));
+        assert_eq!(text.text(), "hello world");
+        assert_eq!(text.selection_range(), Some((0, 5)));
+
+        let cut = text.cut();
+        assert_eq!(cut, Some(String::from("hello")));
+        assert_eq!(text.text(), " world");
+        assert_eq!(text.selection_range(), None);
+        assert_eq!(text.index(), 0);
+
+        text.end();
+        text.paste(" again");
+        assert_eq!(text.text(), " world again");
+    }
+
+    #[test]
+    fn test_text_field_typing_replaces_selection() {
+        let mut text = TextField::new();
+        text.set_text(&String::from("hello world"));
+        text.home();
+        text.select_word_right();
+        assert_eq!(text.selected_text(), Some(String::from("hello")));
+
+        text.insert_character('H');
+        assert_eq!(text.text(), "H world");
+        assert_eq!(text.index(), 1);
+        assert_eq!(text.selection_range(), None);
+
+        text.set_text(&String::from("hello world"));
+        text.home();
+        text.select_word_right();
+        text.paste("Hi");
+        assert_eq!(text.text(), "Hi world");
+        assert_eq!(text.selection_range(), None);
+    }
+
+    #[test]
+    fn test_text_field_masking() {
+        let mut text = TextField::new();
+        text.set_masked('*');
+        text.insert_character('h');
+        text.insert_character('i');
+        assert_eq!(text.text(), "hi");
+        assert_eq!(text.display_text(), "**");
+
+        text.set_plain();
+        assert_eq!(text.display_text(), "hi");
+
+        // Masking doesn't change how editing or cursor movement works.
+        text.home();
+        text.delete();
+        assert_eq!(text.text(), "i");
+    }
+
+    #[test]
+    fn test_text_field_masking_reveal_last_char() {
+        let mut text = TextField::new();
+        text.set_masked('*');
+        text.set_reveal_last_char(true);
+
+        text.insert_character('a');
+        assert_eq!(text.display_text(), "a");
+
+        text.insert_character('b');
+        assert_eq!(text.display_text(), "*b");
+
+        text.clear_reveal();
+        assert_eq!(text.display_text(), "**");
+
+        // Moving the cursor also ends the reveal, even without an
+        // explicit `clear_reveal()` call.
+        text.insert_character('c');
+        assert_eq!(text.display_text(), "**c");
+        text.left();
+        assert_eq!(text.display_text(), "***");
+
+        // Each grapheme masks to exactly one glyph, combining marks
+        // included.
+        text.set_text(&String::from("cafe\u{0301}"));
+        assert_eq!(text.display_text(), "****");
+    }
 }
@@ -0,0 +1,327 @@
+//! Config-driven theming, in the same spirit as xplr's: every render
+//! function pulls its colors from a named `Theme` slot instead of
+//! hard-coding a `Style`, and `Theme::load` overlays a user's
+//! `theme.toml` (a sibling of `.pcli.conf`/`ptui.conf`, the same way
+//! `Keymap::load` finds its override file) on top of built-in defaults.
+//! `NO_COLOR` collapses every slot to the terminal's own style, so the
+//! TUI stays usable on monochrome terminals.
+
+use std::path::Path;
+use tui::style::{Color, Modifier, Style as TuiStyle};
+
+/// A style with every field optional, so a theme file only needs to
+/// mention what it wants to change. `extend` overlays `other`'s
+/// non-`None` fields on top of `self`, one field at a time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub add_modifier: Option<Modifier>,
+    pub sub_modifier: Option<Modifier>,
+}
+
+impl Style {
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    pub fn fg(mut self, color: Color) -> Style {
+        self.fg = Some(color);
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Style {
+        self.bg = Some(color);
+        self
+    }
+
+    pub fn add_modifier(mut self, modifier: Modifier) -> Style {
+        self.add_modifier = Some(modifier);
+        self
+    }
+
+    pub fn extend(mut self, other: Style) -> Style {
+        if let Some(fg) = other.fg {
+            self.fg = Some(fg);
+        }
+        if let Some(bg) = other.bg {
+            self.bg = Some(bg);
+        }
+        if let Some(add_modifier) = other.add_modifier {
+            self.add_modifier = Some(add_modifier);
+        }
+        if let Some(sub_modifier) = other.sub_modifier {
+            self.sub_modifier = Some(sub_modifier);
+        }
+        self
+    }
+
+    fn from_toml(value: &toml::Value) -> Style {
+        let table = match value.as_table() {
+            Some(table) => table,
+            None => return Style::default(),
+        };
+
+        Style {
+            fg: table
+                .get("fg")
+                .and_then(|v| v.as_str())
+                .and_then(parse_color),
+            bg: table
+                .get("bg")
+                .and_then(|v| v.as_str())
+                .and_then(parse_color),
+            add_modifier: table
+                .get("add_modifier")
+                .and_then(|v| v.as_str())
+                .and_then(parse_modifier),
+            sub_modifier: table
+                .get("sub_modifier")
+                .and_then(|v| v.as_str())
+                .and_then(parse_modifier),
+        }
+    }
+}
+
+/// Converts to the style tui actually renders with, collapsing to the
+/// terminal default when `NO_COLOR` is set.
+impl From<Style> for TuiStyle {
+    fn from(style: Style) -> TuiStyle {
+        if no_color() {
+            return TuiStyle::default();
+        }
+
+        let mut tui_style = TuiStyle::default();
+        if let Some(fg) = style.fg {
+            tui_style = tui_style.fg(fg);
+        }
+        if let Some(bg) = style.bg {
+            tui_style = tui_style.bg(bg);
+        }
+        if let Some(add_modifier) = style.add_modifier {
+            tui_style = tui_style.add_modifier(add_modifier);
+        }
+        if let Some(sub_modifier) = style.sub_modifier {
+            tui_style = tui_style.remove_modifier(sub_modifier);
+        }
+        tui_style
+    }
+}
+
+fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some()
+}
+
+fn parse_color(name: &str) -> Option<Color> {
+    match name.to_lowercase().as_str() {
+        "reset" => Some(Color::Reset),
+        "black" => Some(Color::Black),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        "yellow" => Some(Color::Yellow),
+        "blue" => Some(Color::Blue),
+        "magenta" => Some(Color::Magenta),
+        "cyan" => Some(Color::Cyan),
+        "gray" | "grey" => Some(Color::Gray),
+        "darkgray" | "darkgrey" => Some(Color::DarkGray),
+        "lightred" => Some(Color::LightRed),
+        "lightgreen" => Some(Color::LightGreen),
+        "lightyellow" => Some(Color::LightYellow),
+        "lightblue" => Some(Color::LightBlue),
+        "lightmagenta" => Some(Color::LightMagenta),
+        "lightcyan" => Some(Color::LightCyan),
+        "white" => Some(Color::White),
+        _ => None,
+    }
+}
+
+fn parse_modifier(name: &str) -> Option<Modifier> {
+    match name.to_uppercase().as_str() {
+        "BOLD" => Some(Modifier::BOLD),
+        "DIM" => Some(Modifier::DIM),
+        "ITALIC" => Some(Modifier::ITALIC),
+        "UNDERLINED" => Some(Modifier::UNDERLINED),
+        "SLOW_BLINK" => Some(Modifier::SLOW_BLINK),
+        "RAPID_BLINK" => Some(Modifier::RAPID_BLINK),
+        "REVERSED" => Some(Modifier::REVERSED),
+        "HIDDEN" => Some(Modifier::HIDDEN),
+        "CROSSED_OUT" => Some(Modifier::CROSSED_OUT),
+        _ => None,
+    }
+}
+
+/// The named style slots every render function pulls from, so the
+/// whole TUI's palette lives in one place.
+pub struct Theme {
+    pub title: Style,
+    pub title_accent: Style,
+    pub active_border: Style,
+    pub inactive_border: Style,
+    pub selected_row: Style,
+    pub table_header: Style,
+    pub table_row: Style,
+    pub status_indicator: Style,
+    pub status_mode: Style,
+    pub status_text: Style,
+    pub log_border: Style,
+    pub log_error: Style,
+    pub log_warn: Style,
+    pub log_info: Style,
+    pub log_debug: Style,
+    pub log_trace: Style,
+}
+
+impl Theme {
+    fn defaults() -> Theme {
+        Theme {
+            title: Style::new().fg(Color::White).add_modifier(Modifier::BOLD),
+            title_accent: Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            active_border: Style::new().fg(Color::Yellow),
+            inactive_border: Style::new(),
+            selected_row: Style::new().add_modifier(Modifier::REVERSED),
+            table_header: Style::new().fg(Color::Black).add_modifier(Modifier::BOLD),
+            table_row: Style::new().bg(Color::White),
+            status_indicator: Style::new().fg(Color::Blue),
+            status_mode: Style::new().fg(Color::Black).bg(Color::Yellow),
+            status_text: Style::new().fg(Color::Green),
+            log_border: Style::new().fg(Color::White).bg(Color::Black),
+            log_error: Style::new().fg(Color::Red),
+            log_warn: Style::new().fg(Color::Yellow),
+            log_info: Style::new().fg(Color::Cyan),
+            log_debug: Style::new().fg(Color::Green),
+            log_trace: Style::new().fg(Color::Magenta),
+        }
+    }
+
+    /// Builds the theme from built-in defaults, then overlays any
+    /// `[theme]` slots found in the `theme.toml` sibling of
+    /// `config_path`, the same way `Keymap::load` layers `ptui.conf`.
+    pub fn load(config_path: &str) -> Theme {
+        let mut theme = Theme::defaults();
+
+        if let Some(theme_path) = sibling_theme_toml(config_path) {
+            if let Ok(contents) = std::fs::read_to_string(theme_path) {
+                theme.overlay_from_toml(&contents);
+            }
+        }
+
+        theme
+    }
+
+    fn overlay_from_toml(&mut self, contents: &str) {
+        let parsed: toml::Value = match contents.parse() {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+
+        let table = match parsed.get("theme").and_then(|v| v.as_table()) {
+            Some(table) => table,
+            None => return,
+        };
+
+        macro_rules! overlay {
+            ($field:ident, $name:literal) => {
+                if let Some(value) = table.get($name) {
+                    self.$field = self.$field.extend(Style::from_toml(value));
+                }
+            };
+        }
+
+        overlay!(title, "title");
+        overlay!(title_accent, "title-accent");
+        overlay!(active_border, "active-border");
+        overlay!(inactive_border, "inactive-border");
+        overlay!(selected_row, "selected-row");
+        overlay!(table_header, "table-header");
+        overlay!(table_row, "table-row");
+        overlay!(status_indicator, "status-indicator");
+        overlay!(status_mode, "status-mode");
+        overlay!(status_text, "status-text");
+        overlay!(log_border, "log-border");
+        overlay!(log_error, "log-error");
+        overlay!(log_warn, "log-warn");
+        overlay!(log_info, "log-info");
+        overlay!(log_debug, "log-debug");
+        overlay!(log_trace, "log-trace");
+    }
+}
+
+fn sibling_theme_toml(config_path: &str) -> Option<std::path::PathBuf> {
+    let parent = Path::new(config_path).parent()?;
+    Some(parent.join("theme.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extend_overlays_only_the_fields_that_are_set() {
+        let base = Style::new().fg(Color::White).bg(Color::Black);
+        let overlay = Style::new().fg(Color::Yellow);
+
+        let extended = base.extend(overlay);
+        assert_eq!(extended.fg, Some(Color::Yellow));
+        assert_eq!(extended.bg, Some(Color::Black));
+        assert_eq!(extended.add_modifier, None);
+    }
+
+    #[test]
+    fn test_parse_color_is_case_insensitive_and_rejects_unknown_names() {
+        assert_eq!(parse_color("Yellow"), Some(Color::Yellow));
+        assert_eq!(parse_color("DARKGRAY"), Some(Color::DarkGray));
+        assert_eq!(parse_color("lightgreen"), Some(Color::LightGreen));
+        assert_eq!(parse_color("ultraviolet"), None);
+    }
+
+    #[test]
+    fn test_parse_modifier_is_case_insensitive_and_rejects_unknown_names() {
+        assert_eq!(parse_modifier("bold"), Some(Modifier::BOLD));
+        assert_eq!(parse_modifier("Reversed"), Some(Modifier::REVERSED));
+        assert_eq!(parse_modifier("glowing"), None);
+    }
+
+    #[test]
+    fn test_style_from_toml_reads_every_field() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            fg = "red"
+            bg = "black"
+            add_modifier = "bold"
+            sub_modifier = "dim"
+            "#,
+        )
+        .unwrap();
+
+        let style = Style::from_toml(&value);
+        assert_eq!(style.fg, Some(Color::Red));
+        assert_eq!(style.bg, Some(Color::Black));
+        assert_eq!(style.add_modifier, Some(Modifier::BOLD));
+        assert_eq!(style.sub_modifier, Some(Modifier::DIM));
+    }
+
+    #[test]
+    fn test_style_from_toml_defaults_non_table_values_to_empty() {
+        let value = toml::Value::String("not a table".to_string());
+        assert_eq!(Style::from_toml(&value), Style::default());
+    }
+
+    // `NO_COLOR` is read from a process-global env var, so this test
+    // owns every assertion that depends on it to avoid racing with
+    // other tests in other threads over the same var.
+    #[test]
+    fn test_no_color_collapses_every_style_to_the_terminal_default() {
+        std::env::remove_var("NO_COLOR");
+        let themed = Style::new().fg(Color::Red).bg(Color::Black);
+        let tui_style: TuiStyle = themed.into();
+        assert_eq!(
+            tui_style,
+            TuiStyle::default().fg(Color::Red).bg(Color::Black)
+        );
+
+        std::env::set_var("NO_COLOR", "1");
+        let collapsed: TuiStyle = themed.into();
+        assert_eq!(collapsed, TuiStyle::default());
+        std::env::remove_var("NO_COLOR");
+    }
+}
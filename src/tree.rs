@@ -0,0 +1,223 @@
+//! Flattens Physna's folder hierarchy into a depth-tracked, pre-order
+//! list for tree-style rendering (after dirbuilder's depth-tracked
+//! `Item` list and fm's tree display). `build_tree` only ever includes a
+//! folder's children once that folder is in `expanded`, so collapsing a
+//! node is just a matter of leaving its subtree out of the flattened
+//! list on the next rebuild.
+
+use crate::fuzzy::Searchable;
+use pcli::model::Folder;
+use std::collections::{HashMap, HashSet};
+
+/// A folder as it appears in the flattened tree view: its place in the
+/// hierarchy (`depth`), whether it has anything to expand into, and the
+/// branch glyphs needed to draw it (`is_last_sibling`, and whether each
+/// ancestor still has more siblings below it, for the `│`/` ` rails).
+#[derive(Debug, Clone)]
+pub struct FolderNode {
+    pub folder: Folder,
+    pub depth: usize,
+    pub has_children: bool,
+    pub expanded: bool,
+    is_last_sibling: bool,
+    ancestor_has_more_siblings: Vec<bool>,
+}
+
+impl FolderNode {
+    /// The tree-branch prefix (`"│  "`/`"   "` rails plus a trailing
+    /// `"├─ "` or `"└─ "`) to draw before this folder's name.
+    pub fn branch_prefix(&self) -> String {
+        let mut prefix = String::new();
+        for has_more in &self.ancestor_has_more_siblings {
+            prefix.push_str(if *has_more { "│  " } else { "   " });
+        }
+        if self.depth > 0 {
+            prefix.push_str(if self.is_last_sibling {
+                "└─ "
+            } else {
+                "├─ "
+            });
+        }
+        prefix
+    }
+
+    /// A small indicator of expand state, for folders that have
+    /// children to expand into.
+    pub fn expand_glyph(&self) -> &'static str {
+        if !self.has_children {
+            "  "
+        } else if self.expanded {
+            "▾ "
+        } else {
+            "▸ "
+        }
+    }
+}
+
+impl Searchable for FolderNode {
+    fn search_text(&self) -> &str {
+        &self.folder.name
+    }
+}
+
+/// Builds the pre-order flattening of `folders` by parent/child
+/// relationship (a folder with no `parent_id` is a root), descending
+/// into a folder's children only while its id is in `expanded`.
+pub fn build_tree(folders: &[Folder], expanded: &HashSet<u32>) -> Vec<FolderNode> {
+    let mut children_of: HashMap<Option<u32>, Vec<&Folder>> = HashMap::new();
+    for folder in folders {
+        children_of
+            .entry(folder.parent_id)
+            .or_default()
+            .push(folder);
+    }
+    for siblings in children_of.values_mut() {
+        siblings.sort();
+    }
+
+    let mut nodes = Vec::with_capacity(folders.len());
+    if let Some(roots) = children_of.get(&None) {
+        let last_index = roots.len().saturating_sub(1);
+        for (index, root) in roots.iter().enumerate() {
+            flatten(
+                root,
+                0,
+                &[],
+                index == last_index,
+                &children_of,
+                expanded,
+                &mut nodes,
+            );
+        }
+    }
+    nodes
+}
+
+fn flatten<'a>(
+    folder: &'a Folder,
+    depth: usize,
+    ancestor_has_more_siblings: &[bool],
+    is_last_sibling: bool,
+    children_of: &HashMap<Option<u32>, Vec<&'a Folder>>,
+    expanded: &HashSet<u32>,
+    nodes: &mut Vec<FolderNode>,
+) {
+    let children = children_of.get(&Some(folder.id));
+    let has_children = children.map_or(false, |c| !c.is_empty());
+    let node_expanded = expanded.contains(&folder.id);
+
+    nodes.push(FolderNode {
+        folder: folder.clone(),
+        depth,
+        has_children,
+        expanded: node_expanded,
+        is_last_sibling,
+        ancestor_has_more_siblings: ancestor_has_more_siblings.to_vec(),
+    });
+
+    if node_expanded {
+        if let Some(children) = children {
+            let mut child_ancestors = ancestor_has_more_siblings.to_vec();
+            child_ancestors.push(!is_last_sibling);
+            let last_index = children.len().saturating_sub(1);
+            for (index, child) in children.iter().enumerate() {
+                flatten(
+                    child,
+                    depth + 1,
+                    &child_ancestors,
+                    index == last_index,
+                    children_of,
+                    expanded,
+                    nodes,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn folder(id: u32, name: &str, parent_id: Option<u32>) -> Folder {
+        Folder {
+            id,
+            name: name.to_string(),
+            parent_id,
+        }
+    }
+
+    #[test]
+    fn test_build_tree_flattens_only_expanded_branches() {
+        let folders = vec![
+            folder(1, "root-a", None),
+            folder(2, "root-b", None),
+            folder(3, "child-a1", Some(1)),
+            folder(4, "child-a2", Some(1)),
+        ];
+
+        // Nothing expanded: only the two roots show up.
+        let collapsed = build_tree(&folders, &HashSet::new());
+        let names: Vec<&str> = collapsed.iter().map(|n| n.folder.name.as_str()).collect();
+        assert_eq!(names, vec!["root-a", "root-b"]);
+        assert!(collapsed[0].has_children);
+        assert!(!collapsed[0].expanded);
+        assert!(!collapsed[1].has_children);
+
+        // Expanding root-a pulls its children in right after it.
+        let mut expanded = HashSet::new();
+        expanded.insert(1);
+        let tree = build_tree(&folders, &expanded);
+        let names: Vec<&str> = tree.iter().map(|n| n.folder.name.as_str()).collect();
+        assert_eq!(names, vec!["root-a", "child-a1", "child-a2", "root-b"]);
+        assert_eq!(tree[1].depth, 1);
+        assert_eq!(tree[2].depth, 1);
+    }
+
+    #[test]
+    fn test_branch_prefix_marks_last_sibling_and_ancestor_rails() {
+        let folders = vec![
+            folder(1, "root-a", None),
+            folder(2, "root-b", None),
+            folder(3, "child-a1", Some(1)),
+            folder(4, "child-a2", Some(1)),
+        ];
+        let mut expanded = HashSet::new();
+        expanded.insert(1);
+        let tree = build_tree(&folders, &expanded);
+
+        // A root has no branch prefix at all.
+        assert_eq!(tree[0].branch_prefix(), "");
+        // root-a has a sibling (root-b) below it, so its children draw
+        // a continuing "│  " rail ahead of their own branch glyph: the
+        // first child still has a sibling below it ("├─ "), the second
+        // is the last child of root-a ("└─ ").
+        assert_eq!(tree[1].branch_prefix(), "│  ├─ ");
+        assert_eq!(tree[2].branch_prefix(), "│  └─ ");
+        // root-b is itself a root again, so no prefix either.
+        assert_eq!(tree[3].branch_prefix(), "");
+    }
+
+    #[test]
+    fn test_branch_prefix_draws_a_rail_for_deeper_nesting() {
+        let folders = vec![
+            folder(1, "root", None),
+            folder(2, "child", Some(1)),
+            folder(3, "grandchild-a", Some(2)),
+            folder(4, "grandchild-b", Some(2)),
+        ];
+        let mut expanded = HashSet::new();
+        expanded.insert(1);
+        expanded.insert(2);
+        let tree = build_tree(&folders, &expanded);
+
+        // "root" is the only (and therefore last) root, so its child's
+        // branch prefix carries one blank rail ("   ") rather than a
+        // continuing "│  ", then "└─ " since "child" is its only child.
+        assert_eq!(tree[1].branch_prefix(), "   └─ ");
+        // Its children (the grandchildren) inherit that blank rail plus
+        // one more for "child" itself being their last-sibling parent.
+        assert_eq!(tree[2].branch_prefix(), "      ├─ ");
+        assert_eq!(tree[3].branch_prefix(), "      └─ ");
+    }
+}
@@ -0,0 +1,185 @@
+//! A background worker for the slow, network-bound parts of talking to
+//! Physna, in the spirit of yazi's task pool: the render thread sends a
+//! `Command` and moves on immediately, while the worker runs it on its
+//! own tokio runtime and reports back over an async channel that
+//! `State` drains once per frame. This is what keeps the keyboard
+//! responsive while `get_list_of_folders`/`list_all_models` are in
+//! flight.
+
+use log::error;
+use pcli::model::{Folder, Model};
+use pcli::service::Api;
+use std::thread;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+pub enum Command {
+    /// Replaces the API client the worker issues requests with, sent
+    /// once `State::initialize_service` has built one.
+    SetApi(Api),
+    LoadFolders,
+    LoadModels(u32),
+    LoadThumbnail(String),
+}
+
+pub enum WorkerEvent {
+    Folders(Result<Vec<Folder>, String>),
+    Models {
+        folder_id: u32,
+        result: Result<Vec<Model>, String>,
+    },
+    Thumbnail {
+        uuid: String,
+        result: Result<Vec<u8>, String>,
+    },
+}
+
+pub struct Worker {
+    commands: UnboundedSender<Command>,
+    events: UnboundedReceiver<WorkerEvent>,
+}
+
+impl Worker {
+    /// Starts the worker on a dedicated thread with its own
+    /// single-threaded tokio runtime, and returns the handle `State`
+    /// uses to send it commands and poll its results.
+    pub fn spawn() -> Worker {
+        let (command_tx, command_rx) = mpsc::unbounded_channel();
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+
+        thread::spawn(move || {
+            let runtime = match tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+            {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    error!("Failed to start the background worker: {}", e);
+                    return;
+                }
+            };
+
+            runtime.block_on(run(command_rx, event_tx));
+        });
+
+        Worker {
+            commands: command_tx,
+            events: event_rx,
+        }
+    }
+
+    pub fn send(&self, command: Command) {
+        let _ = self.commands.send(command);
+    }
+
+    /// Returns one pending result without blocking, if the worker has
+    /// finished anything since the last call.
+    pub fn poll(&mut self) -> Option<WorkerEvent> {
+        self.events.try_recv().ok()
+    }
+}
+
+async fn run(mut commands: UnboundedReceiver<Command>, events: UnboundedSender<WorkerEvent>) {
+    let mut api: Option<Api> = None;
+
+    while let Some(command) = commands.recv().await {
+        match command {
+            Command::SetApi(new_api) => api = Some(new_api),
+            Command::LoadFolders => {
+                let result = match &api {
+                    Some(api) => api
+                        .get_list_of_folders()
+                        .map(|folders| folders.folders)
+                        .map_err(|e| e.to_string()),
+                    None => Err(String::from("Not connected to Physna")),
+                };
+                let _ = events.send(WorkerEvent::Folders(result));
+            }
+            Command::LoadModels(folder_id) => {
+                let result = match &api {
+                    Some(api) => api
+                        .list_all_models(vec![folder_id], None, false)
+                        .map(|models| models.models)
+                        .map_err(|e| e.to_string()),
+                    None => Err(String::from("Not connected to Physna")),
+                };
+                let _ = events.send(WorkerEvent::Models { folder_id, result });
+            }
+            Command::LoadThumbnail(uuid) => {
+                let result = match &api {
+                    Some(api) => api.get_model_thumbnail(&uuid).map_err(|e| e.to_string()),
+                    None => Err(String::from("Not connected to Physna")),
+                };
+                let _ = events.send(WorkerEvent::Thumbnail { uuid, result });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    /// Polls `worker` until an event arrives or `timeout` elapses; the
+    /// worker runs on its own thread, so a bare `poll()` right after
+    /// `send()` can race it.
+    fn poll_until(worker: &mut Worker, timeout: Duration) -> Option<WorkerEvent> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(event) = worker.poll() {
+                return Some(event);
+            }
+            if Instant::now() >= deadline {
+                return None;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn test_load_folders_without_an_api_reports_not_connected() {
+        let mut worker = Worker::spawn();
+        worker.send(Command::LoadFolders);
+
+        match poll_until(&mut worker, Duration::from_secs(2)) {
+            Some(WorkerEvent::Folders(Err(message))) => {
+                assert_eq!(message, "Not connected to Physna");
+            }
+            other => panic!("expected Folders(Err(..)), got {:?}", other.is_some()),
+        }
+    }
+
+    #[test]
+    fn test_load_models_without_an_api_reports_not_connected_and_keeps_the_folder_id() {
+        let mut worker = Worker::spawn();
+        worker.send(Command::LoadModels(42));
+
+        match poll_until(&mut worker, Duration::from_secs(2)) {
+            Some(WorkerEvent::Models { folder_id, result }) => {
+                assert_eq!(folder_id, 42);
+                assert_eq!(result.unwrap_err(), "Not connected to Physna");
+            }
+            other => panic!("expected Models {{ .. }}, got {:?}", other.is_some()),
+        }
+    }
+
+    #[test]
+    fn test_load_thumbnail_without_an_api_reports_not_connected_and_keeps_the_uuid() {
+        let mut worker = Worker::spawn();
+        worker.send(Command::LoadThumbnail("uuid-1".to_string()));
+
+        match poll_until(&mut worker, Duration::from_secs(2)) {
+            Some(WorkerEvent::Thumbnail { uuid, result }) => {
+                assert_eq!(uuid, "uuid-1");
+                assert_eq!(result.unwrap_err(), "Not connected to Physna");
+            }
+            other => panic!("expected Thumbnail {{ .. }}, got {:?}", other.is_some()),
+        }
+    }
+
+    #[test]
+    fn test_poll_returns_none_when_nothing_has_finished() {
+        let mut worker = Worker::spawn();
+        assert!(worker.poll().is_none());
+    }
+}